@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use crate::runner::{DayResult, Part};
+
+/// Expected answers as recorded in `data/answers.txt`.
+///
+/// Each line holds a key such as `day7` or `day7p2` followed by the answer,
+/// or `-` if that part has not been solved yet.
+pub struct Answers {
+    answers: HashMap<(u32, Part), Option<String>>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers, String> {
+        let mut answers = HashMap::new();
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let key = fields.next().unwrap();
+            let value = fields
+                .next()
+                .ok_or_else(|| format!("line {}: missing answer for {}", index + 1, key))?;
+            let (day, part) = match key.strip_suffix("p2") {
+                Some(day) => (day, Part::Two),
+                None => (key, Part::One),
+            };
+            let day = day
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u32>().ok())
+                .ok_or_else(|| format!("line {}: invalid key {}", index + 1, key))?;
+            let value = if value == "-" {
+                None
+            } else {
+                Some(value.to_string())
+            };
+            answers.insert((day, part), value);
+        }
+        Ok(Answers { answers })
+    }
+
+    /// Every day listed, in ascending order.
+    pub fn days(&self) -> Vec<u32> {
+        let mut days: Vec<_> = self.answers.keys().map(|(day, _)| *day).collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// The recorded answer, or `None` if the part is unsolved or not listed.
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part))?.as_deref()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No answer has been recorded for this part yet.
+    Unsolved,
+}
+
+pub fn check(answers: &Answers, result: &DayResult) -> Vec<(Part, Outcome)> {
+    result
+        .parts
+        .iter()
+        .map(|part| {
+            let outcome = match answers.get(result.day, part.part) {
+                None => Outcome::Unsolved,
+                Some(expected) if part.answer.as_deref() == Some(expected) => Outcome::Pass,
+                Some(expected) => Outcome::Fail {
+                    expected: expected.to_string(),
+                    actual: part.answer.clone(),
+                },
+            };
+            (part.part, outcome)
+        })
+        .collect()
+}
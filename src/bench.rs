@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use std::{env, fmt, fs};
+
+use crate::error::ParseError;
+use crate::runner::{Entry, Part};
+
+/// A timed phase of a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    fn from_part(part: Part) -> Phase {
+        match part {
+            Part::One => Phase::Part1,
+            Part::Two => Phase::Part2,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        })
+    }
+}
+
+impl std::str::FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Part1),
+            "part2" => Ok(Phase::Part2),
+            _ => Err(format!("invalid phase: {}", s)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        assert!(!samples.is_empty());
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Runs day `entry` on `input` `runs` times, timing parsing and each part separately.
+pub fn bench(
+    entry: &Entry,
+    input: &str,
+    runs: usize,
+) -> Result<BTreeMap<Phase, Stats>, ParseError> {
+    let mut samples: BTreeMap<Phase, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..runs.max(1) {
+        let result = entry.run(input, &Part::ALL)?;
+        samples
+            .entry(Phase::Parse)
+            .or_default()
+            .push(result.parse_time);
+        for part in result.parts {
+            samples
+                .entry(Phase::from_part(part.part))
+                .or_default()
+                .push(part.time);
+        }
+    }
+    Ok(samples
+        .into_iter()
+        .map(|(phase, mut samples)| (phase, Stats::from_samples(&mut samples)))
+        .collect())
+}
+
+/// The machine and build timings are taken on, e.g. `box x86_64-linux
+/// release`. Timings only compare on the same machine and build profile.
+pub fn host() -> String {
+    let name = fs::read_to_string("/proc/sys/kernel/hostname")
+        .ok()
+        .or_else(|| env::var("HOSTNAME").ok())
+        .or_else(|| env::var("COMPUTERNAME").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| String::from("unknown"));
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    format!(
+        "{} {}-{} {}",
+        name,
+        env::consts::ARCH,
+        env::consts::OS,
+        profile
+    )
+}
+
+/// Recorded timings to compare against, stored as a `host <tag>` line with
+/// the [`host`] they were taken on, then one phase per line as
+/// `day<N> <phase> <min ns> <median ns> <max ns>`.
+#[derive(Default)]
+pub struct Baseline {
+    /// The host the timings were taken on, if recorded.
+    pub host: Option<String>,
+    stats: BTreeMap<(u32, Phase), Stats>,
+}
+
+impl Baseline {
+    pub fn parse(text: &str) -> Result<Baseline, String> {
+        let mut host = None;
+        let mut stats = BTreeMap::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(tag) = line.strip_prefix("host ") {
+                host = Some(tag.trim().to_string());
+                continue;
+            }
+            let error = || format!("line {}: invalid baseline entry: {}", index + 1, line);
+            let fields: Vec<_> = line.split_whitespace().collect();
+            if fields.len() != 5 {
+                return Err(error());
+            }
+            let day = fields[0]
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u32>().ok())
+                .ok_or_else(error)?;
+            let phase = fields[1].parse::<Phase>().map_err(|_| error())?;
+            let nanos = |field: &str| field.parse::<u64>().map(Duration::from_nanos);
+            let (min, median, max) = match (nanos(fields[2]), nanos(fields[3]), nanos(fields[4])) {
+                (Ok(min), Ok(median), Ok(max)) => (min, median, max),
+                _ => return Err(error()),
+            };
+            stats.insert((day, phase), Stats { min, median, max });
+        }
+        Ok(Baseline { host, stats })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stats.is_empty()
+    }
+
+    pub fn get(&self, day: u32, phase: Phase) -> Option<&Stats> {
+        self.stats.get(&(day, phase))
+    }
+
+    pub fn insert(&mut self, day: u32, phase: Phase, stats: Stats) {
+        self.stats.insert((day, phase), stats);
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# day phase min_ns median_ns max_ns")?;
+        if let Some(host) = &self.host {
+            writeln!(f, "host {}", host)?;
+        }
+        for ((day, phase), stats) in self.stats.iter() {
+            writeln!(
+                f,
+                "day{} {} {} {} {}",
+                day,
+                phase,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Relative change of the median from `baseline` to `current`, in percent.
+pub fn change(baseline: &Stats, current: &Stats) -> f64 {
+    let baseline = baseline.median.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.median.as_secs_f64() - baseline) / baseline * 100.0
+}
@@ -0,0 +1,1012 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use aoc2018::answers::{self, Answers, Outcome};
+use aoc2018::bench::{self, Baseline};
+use aoc2018::error::ParseError;
+use aoc2018::gen;
+use aoc2018::history::{self, Record};
+use aoc2018::input::{self, InputSet, Source};
+use aoc2018::json::Value;
+use aoc2018::params::{self, Overrides, Values};
+use aoc2018::pool;
+use aoc2018::rng::Rng;
+use aoc2018::runner::{self, DayResult, Entry, Part};
+use aoc2018::scaffold;
+use aoc2018::step;
+#[cfg(feature = "trace")]
+use aoc2018::trace;
+use aoc2018::watch::Watcher;
+
+const USAGE: &str = "\
+Usage:
+    aoc run <day> [--part <1|2>] [--input <path|-> | --set <name>]
+            [--format <text|json>] [--explain] [--trace]
+            [--trace-format <text|jsonl>] [--param <name=value>...]
+            [--params <path>]
+    aoc run --all [--set <name>] [--format <text|json>] [--explain] [--jobs <n>]
+            [--trace] [--params <path>]
+    aoc gen <day> [--seed <n>] [--size <n>]
+    aoc bench [day...] [--runs <n>] [--threshold <percent>] [--save]
+    aoc verify [--set <name> | --all-sets] [--jobs <n>]
+    aoc watch <day> [--set <name>] [--input <path>] [--interval <ms>]
+    aoc step <day> [--input <path> | --set <name>] [--param <name=value>...]
+            [--params <path>]
+    aoc check-input <day> [--input <path|-> | --set <name>]
+    aoc history <day> [--part <1|2>]
+    aoc params <day>
+    aoc new <day>
+    aoc list
+
+Inputs are read from $AOC_DATA_DIR (default: ./data) unless --input is given,
+where - reads from stdin. --format json prints the answers along with named
+intermediate values as a JSON array with one object per day.
+
+--set reads the named input set instead, with its own answers:
+data/<name>/dayN.txt and data/<name>/answers.txt. verify --all-sets checks
+every set with an answers.txt, the default one first, grouping the results by
+set.
+
+--jobs runs up to n days at once (default: 1); results are still printed in
+day order, with the total time of each day.
+
+--explain prints how each answer was derived below it, such as the chosen
+guard and minute in day 4 or the crashes before the last cart in day 13, and
+adds it to --format json as an explanation array of lines. Days without an
+explanation of their own list their named values.
+
+--trace writes the events of the days to stderr as they are solved, such as
+worker assignments in day 7 or cart moves in day 13, one per line.
+--trace-format jsonl (which implies --trace) writes them as JSON objects.
+
+gen prints a random input for the day, with about --size lines or elements
+(default: 100), reproducible from --seed (default: 0).
+
+watch re-runs the day whenever its input, data/answers.txt or the alternate
+--input changes, checking the answers to its input against data/answers.txt.
+Files are polled every --interval milliseconds (default: 500).
+
+step reads commands from stdin to step through the simulation of days 7, 9,
+10, 12 and 13: step [n], run-until <name> <op> <value>, run-until done, show,
+back [n] and quit.
+
+Every run appends its answers and times to data/history.txt, with a hash of
+the input and the checked out commit, and warns when an answer changed since
+the last run on the same input. history lists the runs of a day.
+
+Puzzle constants such as the number of workers in day 7 are parameters,
+listed with their defaults by params. --param name=value overrides one for
+the day, and --params reads [dayN] sections of name = value lines from a file,
+such as aoc.toml. Runs with overridden parameters are left out of the history.
+
+Inputs are normalized when read: CRLF line endings, trailing whitespace and
+blank lines at the end are removed. check-input lists what normalizing fixes
+in the input, then every error found parsing it, and fails if there is any.
+
+show in step needs aoc built with the visualize feature, and --trace the trace
+feature, e.g. cargo run --features visualize,trace. Every day is built by
+default; --no-default-features with week1 to week4 and heavy (days 9 and 14)
+picks which.
+
+new starts a day: src/dayN.rs with a Solution solving neither part and
+ignored tests for the puzzle example, registered in src/lib.rs and the
+runner, and an empty input file. Its answers are added to data/answers.txt
+once found.
+
+bench compares median times against data/bench_baseline.txt and fails when a
+phase is slower by more than the threshold (default: 10%); --save records the
+new timings as the baseline. Timings only compare on the same machine and
+build profile, which the baseline records, with a warning when they differ.";
+
+const DEFAULT_RUNS: usize = 5;
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+fn parse_day(arg: &str) -> Result<&'static Entry, String> {
+    let day = arg
+        .parse::<u32>()
+        .map_err(|_| format!("invalid day: {}", arg))?;
+    runner::find(day).ok_or_else(|| format!("day {} is not solved", day))
+}
+
+fn parse_part(arg: Option<&String>) -> Result<Part, String> {
+    match arg.map(String::as_str) {
+        Some("1") => Ok(Part::One),
+        Some("2") => Ok(Part::Two),
+        Some(arg) => Err(format!("invalid part: {}", arg)),
+        None => Err(String::from("missing value for --part")),
+    }
+}
+
+fn print_header() {
+    println!(
+        "{:>3}  {:<5}  {:<30}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+}
+
+fn print_result(result: &DayResult, total: bool, explain: bool) {
+    println!(
+        "{:>3}  {:<5}  {:<30}  {:>12.3?}",
+        result.day, "parse", "", result.parse_time
+    );
+    for part in result.parts.iter() {
+        let answer = part.answer.as_deref().unwrap_or("No solution found");
+        // Multi-line answers (e.g. day 10 messages) are printed below their row
+        let (cell, block) = if answer.contains('\n') {
+            ("", Some(answer))
+        } else {
+            (answer, None)
+        };
+        println!(
+            "{:>3}  {:<5}  {:<30}  {:>12.3?}",
+            result.day, part.part, cell, part.time
+        );
+        if let Some(block) = block {
+            for line in block.lines() {
+                println!("{:>3}  {:<5}  {}", "", "", line);
+            }
+        }
+        if explain {
+            for line in part.explanation.iter() {
+                println!("{:>3}  {:<5}  - {}", "", "", line);
+            }
+        }
+    }
+    if total {
+        println!(
+            "{:>3}  {:<5}  {:<30}  {:>12.3?}",
+            result.day,
+            "total",
+            "",
+            result.time()
+        );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn parse_format(arg: Option<&String>) -> Result<Format, String> {
+    match arg.map(String::as_str) {
+        Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        Some(arg) => Err(format!("invalid format: {}", arg)),
+        None => Err(String::from("missing value for --format")),
+    }
+}
+
+#[cfg(feature = "trace")]
+fn parse_trace_format(arg: Option<&String>) -> Result<trace::Format, String> {
+    match arg.map(String::as_str) {
+        Some("text") => Ok(trace::Format::Text),
+        Some("jsonl") => Ok(trace::Format::Jsonl),
+        Some(arg) => Err(format!("invalid trace format: {}", arg)),
+        None => Err(String::from("missing value for --trace-format")),
+    }
+}
+
+/// Why a day could not be run.
+enum Failure {
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+fn solve(entry: &Entry, source: &Source, parts: &[Part]) -> Result<DayResult, Failure> {
+    let input = source.read(entry.day).map_err(Failure::Read)?;
+    entry.run(&input, parts).map_err(Failure::Parse)
+}
+
+fn parse_jobs(arg: Option<&String>) -> Result<usize, String> {
+    let jobs = parse_number("--jobs", arg)?;
+    if jobs == 0 {
+        return Err(String::from("invalid value for --jobs: 0"));
+    }
+    Ok(jobs)
+}
+
+/// The run history, warning about answers that changed for the same input.
+/// Problems with the history are reported without failing the run.
+struct RunLog {
+    records: Vec<Record>,
+    commit: Option<String>,
+}
+
+impl RunLog {
+    fn open() -> RunLog {
+        let records = history::read(&input::history_path()).unwrap_or_else(|e| {
+            eprintln!("warning: cannot read the run history: {}", e);
+            Vec::new()
+        });
+        RunLog {
+            records,
+            commit: history::git_commit(Path::new(env!("CARGO_MANIFEST_DIR"))),
+        }
+    }
+
+    fn record(&mut self, result: &DayResult, input_hash: u64) {
+        let records = Record::of(result, input_hash, self.commit.as_deref());
+        for record in records.iter() {
+            if let Some(earlier) = history::regression(&self.records, record) {
+                warn_changed(earlier, record);
+            }
+        }
+        let path = input::history_path();
+        if let Err(e) = history::append(&path, &records) {
+            eprintln!(
+                "warning: cannot record the run in {}: {}",
+                path.display(),
+                e
+            );
+        }
+        self.records.extend(records);
+    }
+}
+
+fn short_commit(commit: Option<&str>) -> &str {
+    commit.map_or("-", |commit| &commit[..commit.len().min(7)])
+}
+
+fn warn_changed(earlier: &Record, record: &Record) {
+    let answer = |record: &Record| {
+        record
+            .answer
+            .clone()
+            .unwrap_or_else(|| String::from("no solution"))
+    };
+    eprintln!("{}", "!".repeat(72));
+    eprintln!(
+        "WARNING: day {} part {} answer changed for an unchanged input ({:016x})",
+        record.day, record.part, record.input_hash
+    );
+    eprintln!(
+        "  before: {} ({}, commit {})",
+        answer(earlier),
+        history::utc(earlier.timestamp),
+        short_commit(earlier.commit.as_deref())
+    );
+    eprintln!(
+        "  now:    {} (commit {})",
+        answer(record),
+        short_commit(record.commit.as_deref())
+    );
+    eprintln!("{}", "!".repeat(72));
+}
+
+fn show_history(args: &[String]) -> Result<(), String> {
+    let mut entry = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => parts = vec![parse_part(args.next())?],
+            arg => entry = Some(parse_day(arg)?),
+        }
+    }
+    let entry = entry.ok_or("no day selected")?;
+    let path = input::history_path();
+    let records =
+        history::read(&path).map_err(|e| format!("cannot read the run history: {}", e))?;
+    println!(
+        "{:<19}  {:<4}  {:<8}  {:<7}  {:>12}  Answer",
+        "When", "Part", "Input", "Commit", "Time"
+    );
+    let mut shown = 0;
+    for (index, record) in records.iter().enumerate() {
+        if record.day != entry.day || !parts.contains(&record.part) {
+            continue;
+        }
+        // Compared with the previous run of the same part
+        let previous = records[..index]
+            .iter()
+            .rev()
+            .find(|earlier| (earlier.day, earlier.part) == (record.day, record.part));
+        let note = match previous {
+            Some(previous) if previous.input_hash != record.input_hash => "  (new input)",
+            Some(previous) if previous.answer != record.answer => "  <- CHANGED for the same input",
+            _ => "",
+        };
+        let answer = record.answer.as_deref().unwrap_or("no solution");
+        let answer = match answer.split_once('\n') {
+            Some((first, _)) => format!("{} ...", first),
+            None => answer.to_string(),
+        };
+        println!(
+            "{:<19}  {:<4}  {:<8}  {:<7}  {:>12.3?}  {}{}",
+            history::utc(record.timestamp),
+            record.part,
+            &format!("{:016x}", record.input_hash)[..8],
+            short_commit(record.commit.as_deref()),
+            record.time,
+            answer,
+            note
+        );
+        shown += 1;
+    }
+    if shown == 0 {
+        println!(
+            "no runs of day {} recorded in {}",
+            entry.day,
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Reads parameter overrides from the file at `arg`.
+fn read_params(arg: Option<&String>) -> Result<Overrides, String> {
+    let path = arg.ok_or("missing value for --params")?;
+    let text = fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+    Overrides::parse(&text).map_err(|e| format!("{}: {}", path, e))
+}
+
+/// The values of `overrides` with the `name=value` arguments of --param, which
+/// are for the only day selected, parsed and checked against the parameters of
+/// the days.
+fn add_params(
+    mut overrides: Overrides,
+    args: &[&String],
+    entries: &[&Entry],
+) -> Result<Values, String> {
+    if !args.is_empty() {
+        let [entry] = entries else {
+            return Err(String::from(
+                "--param can only be used with a single day, use --params for several",
+            ));
+        };
+        for arg in args {
+            overrides.set_arg(entry.day, arg)?;
+        }
+    }
+    overrides.values(runner::DAYS)
+}
+
+fn show_params(args: &[String]) -> Result<(), String> {
+    let [arg] = args else {
+        return Err(String::from("usage: aoc params <day>"));
+    };
+    let entry = parse_day(arg)?;
+    if entry.params.is_empty() {
+        println!("day {} has no parameters", entry.day);
+        return Ok(());
+    }
+    println!(
+        "{:<20}  {:<5}  {:>11}  Description",
+        "Name", "Type", "Default"
+    );
+    for param in entry.params {
+        let doc = match param.bounds() {
+            Some(bounds) => format!("{} ({})", param.doc(), bounds),
+            None => param.doc().to_string(),
+        };
+        println!(
+            "{:<20}  {:<5}  {:>11}  {}",
+            param.name(),
+            param.kind(),
+            param.default(),
+            doc
+        );
+    }
+    Ok(())
+}
+
+fn parse_set(arg: Option<&String>) -> Result<InputSet, String> {
+    InputSet::named(arg.ok_or("missing value for --set")?)
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut entries = Vec::new();
+    let mut parts = Part::ALL.to_vec();
+    let mut source = Source::default();
+    let mut format = Format::Text;
+    let mut jobs = 1;
+    let mut explain = false;
+    #[cfg(feature = "trace")]
+    let mut tracing = None;
+    let mut overrides = Overrides::default();
+    let mut param_args = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => entries.extend(runner::DAYS),
+            "--param" => param_args.push(args.next().ok_or("missing value for --param")?),
+            "--params" => overrides = read_params(args.next())?,
+            "--part" => parts = vec![parse_part(args.next())?],
+            "--format" => format = parse_format(args.next())?,
+            "--jobs" => jobs = parse_jobs(args.next())?,
+            "--explain" => explain = true,
+            #[cfg(feature = "trace")]
+            "--trace" => tracing = tracing.or(Some(trace::Format::Text)),
+            #[cfg(feature = "trace")]
+            "--trace-format" => tracing = Some(parse_trace_format(args.next())?),
+            #[cfg(not(feature = "trace"))]
+            "--trace" | "--trace-format" => return Err(without_feature(arg, "trace")),
+            "--set" => source = Source::Set(parse_set(args.next())?),
+            "--input" => {
+                let path = args.next().ok_or("missing value for --input")?;
+                source = Source::from_arg(path);
+            }
+            arg => entries.push(parse_day(arg)?),
+        }
+    }
+    if entries.is_empty() {
+        return Err(String::from("no day selected"));
+    }
+    if !matches!(source, Source::Set(_)) && entries.len() > 1 {
+        return Err(String::from("--input can only be used with a single day"));
+    }
+    let values = add_params(overrides, &param_args, &entries)?;
+    #[cfg(feature = "trace")]
+    if let Some(format) = tracing {
+        trace::start(format, Box::new(io::stderr()));
+    }
+    let total = entries.len() > 1;
+    let mut failed = false;
+    let mut header = false;
+    let mut json = Vec::new();
+    let mut log = values.is_empty().then(RunLog::open);
+    pool::ordered(
+        jobs,
+        &entries,
+        |entry| {
+            let solved = source
+                .read(entry.day)
+                .map_err(Failure::Read)
+                .and_then(|input| {
+                    let result = params::scope(&values, || entry.run(&input, &parts))
+                        .map_err(Failure::Parse)?;
+                    Ok((history::hash(&input), result))
+                });
+            (entry.day, solved)
+        },
+        |(day, solved)| match solved {
+            Ok((input_hash, result)) => {
+                if let Some(log) = log.as_mut() {
+                    log.record(&result, input_hash);
+                }
+                if format == Format::Json {
+                    json.push(result.to_json(explain));
+                    return;
+                }
+                if !header {
+                    print_header();
+                    header = true;
+                }
+                print_result(&result, total, explain);
+            }
+            Err(failure) => {
+                let error = match failure {
+                    Failure::Read(e) => {
+                        eprintln!("day {}: cannot read input: {}", day, e);
+                        format!("cannot read input: {}", e)
+                    }
+                    Failure::Parse(e) => {
+                        eprint!("{}", e.diagnostic(&source.origin(day)));
+                        e.to_string()
+                    }
+                };
+                json.push(Value::object(vec![
+                    ("day", Value::from(day)),
+                    ("error", Value::from(error)),
+                ]));
+                failed = true;
+            }
+        },
+    );
+    if format == Format::Json {
+        println!("{}", Value::Array(json));
+    }
+    if failed {
+        Err(String::from("some inputs could not be read or parsed"))
+    } else {
+        Ok(())
+    }
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let mut entries = Vec::new();
+    let mut runs = DEFAULT_RUNS;
+    let mut threshold = DEFAULT_THRESHOLD;
+    let mut save = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                let value = args.next().ok_or("missing value for --runs")?;
+                runs = value
+                    .parse()
+                    .ok()
+                    .filter(|&runs| runs > 0)
+                    .ok_or_else(|| format!("invalid number of runs: {}", value))?;
+            }
+            "--threshold" => {
+                let value = args.next().ok_or("missing value for --threshold")?;
+                threshold = value
+                    .trim_end_matches('%')
+                    .parse()
+                    .map_err(|_| format!("invalid threshold: {}", value))?;
+            }
+            "--save" => save = true,
+            arg => entries.push(parse_day(arg)?),
+        }
+    }
+    if entries.is_empty() {
+        entries.extend(runner::DAYS);
+    }
+    let path = input::baseline_path();
+    let mut baseline = match fs::read_to_string(&path) {
+        Ok(text) => Baseline::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Baseline::default(),
+        Err(e) => return Err(format!("cannot read {}: {}", path.display(), e)),
+    };
+    let host = bench::host();
+    match &baseline.host {
+        Some(recorded) if *recorded != host => eprintln!(
+            "warning: the baseline was taken on {}, not {}; timings only compare on the \
+             same machine and profile",
+            recorded, host
+        ),
+        None if !baseline.is_empty() => eprintln!(
+            "warning: the baseline does not record the machine it was taken on; timings \
+             only compare on the same machine and profile"
+        ),
+        _ => (),
+    }
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>8}",
+        "Day", "Phase", "Min", "Median", "Max", "Baseline", "Change"
+    );
+    let mut regressions = 0;
+    let mut failed = false;
+    for entry in entries {
+        let stats = match aoc2018::read_input(entry.day) {
+            Ok(input) => bench::bench(entry, &input, runs),
+            Err(e) => {
+                eprintln!("day {}: cannot read input: {}", entry.day, e);
+                failed = true;
+                continue;
+            }
+        };
+        let stats = match stats {
+            Ok(stats) => stats,
+            Err(e) => {
+                eprint!("{}", e.diagnostic(&Source::default().origin(entry.day)));
+                failed = true;
+                continue;
+            }
+        };
+        for (phase, stats) in stats {
+            let (recorded, change) = match baseline.get(entry.day, phase) {
+                Some(recorded) => (
+                    format!("{:.3?}", recorded.median),
+                    format!("{:+.1}%", bench::change(recorded, &stats)),
+                ),
+                None => (String::from("-"), String::new()),
+            };
+            let regressed = baseline
+                .get(entry.day, phase)
+                .is_some_and(|recorded| bench::change(recorded, &stats) > threshold);
+            println!(
+                "{:>3}  {:<5}  {:>12.3?}  {:>12.3?}  {:>12.3?}  {:>12}  {:>8}{}",
+                entry.day,
+                phase,
+                stats.min,
+                stats.median,
+                stats.max,
+                recorded,
+                change,
+                if regressed { "  REGRESSION" } else { "" }
+            );
+            if regressed {
+                regressions += 1;
+            }
+            if save {
+                baseline.insert(entry.day, phase, stats);
+            }
+        }
+    }
+    if save {
+        baseline.host = Some(host);
+        fs::write(&path, baseline.to_string())
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        println!("baseline saved to {}", path.display());
+    }
+    if failed {
+        Err(String::from("some inputs could not be read or parsed"))
+    } else if regressions > 0 {
+        Err(format!(
+            "{} phases are more than {}% slower than the baseline",
+            regressions, threshold
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+const DEFAULT_SIZE: usize = 100;
+
+fn parse_number<T: std::str::FromStr>(option: &str, arg: Option<&String>) -> Result<T, String> {
+    let arg = arg.ok_or_else(|| format!("missing value for {}", option))?;
+    arg.parse()
+        .map_err(|_| format!("invalid value for {}: {}", option, arg))
+}
+
+fn generate(args: &[String]) -> Result<(), String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = DEFAULT_SIZE;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = parse_number("--seed", args.next())?,
+            "--size" => size = parse_number("--size", args.next())?,
+            arg => day = Some(parse_day(arg)?.day),
+        }
+    }
+    let day = day.ok_or("no day selected")?;
+    let input = gen::input(day, &mut Rng::new(seed), size)
+        .ok_or_else(|| format!("no generator for day {}", day))?;
+    print!("{}", input);
+    Ok(())
+}
+
+fn print_outcome(day: u32, part: Part, outcome: &Outcome) {
+    match outcome {
+        Outcome::Pass => println!("day{:<3} {}  PASS", day, part),
+        Outcome::Fail { expected, actual } => println!(
+            "day{:<3} {}  FAIL  expected {}, got {}",
+            day,
+            part,
+            expected,
+            actual.as_deref().unwrap_or("no solution")
+        ),
+        Outcome::Unsolved => println!("day{:<3} {}  UNSOLVED", day, part),
+    }
+}
+
+/// Counts of checked answers.
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unsolved: usize,
+}
+
+impl std::fmt::Display for Tally {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unsolved",
+            self.passed, self.failed, self.unsolved
+        )
+    }
+}
+
+/// Checks every day listed in the answers of `set` against its inputs.
+fn verify_set(set: &InputSet, jobs: usize) -> Result<Tally, String> {
+    let answers = set
+        .read_answers()
+        .map_err(|e| format!("cannot read answers: {}", e))
+        .and_then(|answers| Answers::parse(&answers))?;
+    let source = Source::Set(set.clone());
+    let mut tally = Tally::default();
+    let days = answers.days();
+    pool::ordered(
+        jobs,
+        &days,
+        |&day| {
+            let result = runner::find(day).map(|entry| solve(entry, &source, &Part::ALL));
+            (day, result)
+        },
+        |(day, result)| {
+            let result = match result {
+                Some(Ok(result)) => result,
+                Some(Err(Failure::Read(e))) => {
+                    println!("day{:<3} -  FAIL  cannot read input: {}", day, e);
+                    tally.failed += Part::ALL.len();
+                    return;
+                }
+                Some(Err(Failure::Parse(e))) => {
+                    println!("day{:<3} -  FAIL  {}", day, e);
+                    tally.failed += Part::ALL.len();
+                    return;
+                }
+                None => {
+                    println!("day{:<3} -  UNSOLVED", day);
+                    tally.unsolved += Part::ALL.len();
+                    return;
+                }
+            };
+            for (part, outcome) in answers::check(&answers, &result) {
+                print_outcome(day, part, &outcome);
+                match outcome {
+                    Outcome::Pass => tally.passed += 1,
+                    Outcome::Fail { .. } => tally.failed += 1,
+                    Outcome::Unsolved => tally.unsolved += 1,
+                }
+            }
+        },
+    );
+    println!("{}", tally);
+    Ok(tally)
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let mut jobs = 1;
+    let mut sets = vec![InputSet::default()];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--jobs" => jobs = parse_jobs(args.next())?,
+            "--set" => sets = vec![parse_set(args.next())?],
+            "--all-sets" => {
+                sets = InputSet::all().map_err(|e| format!("cannot list input sets: {}", e))?;
+            }
+            arg => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+    if let [set] = &sets[..] {
+        let tally = verify_set(set, jobs)?;
+        return if tally.failed > 0 {
+            Err(format!(
+                "{} answers do not match {}",
+                tally.failed,
+                set.answers_path().display()
+            ))
+        } else {
+            Ok(())
+        };
+    }
+    // Several sets, each under its own heading
+    let mut total = Tally::default();
+    let mut failing = Vec::new();
+    for (index, set) in sets.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        println!("== {} ({})", set.name(), set.dir().display());
+        match verify_set(set, jobs) {
+            Ok(tally) => {
+                if tally.failed > 0 {
+                    failing.push(set.name());
+                }
+                total.passed += tally.passed;
+                total.failed += tally.failed;
+                total.unsolved += tally.unsolved;
+            }
+            Err(e) => {
+                println!("{}", e);
+                failing.push(set.name());
+            }
+        }
+    }
+    println!("\n{} sets: {}", sets.len(), total);
+    if failing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "answers do not match in sets: {}",
+            failing.join(", ")
+        ))
+    }
+}
+
+fn step(args: &[String]) -> Result<(), String> {
+    let mut entry = None;
+    let mut source = Source::default();
+    let mut overrides = Overrides::default();
+    let mut param_args = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--param" => param_args.push(args.next().ok_or("missing value for --param")?),
+            "--params" => overrides = read_params(args.next())?,
+            "--input" => {
+                let path = args.next().ok_or("missing value for --input")?;
+                source = Source::from_arg(path);
+            }
+            "--set" => source = Source::Set(parse_set(args.next())?),
+            arg => entry = Some(parse_day(arg)?),
+        }
+    }
+    let entry = entry.ok_or("no day selected")?;
+    let values = add_params(overrides, &param_args, &[entry])?;
+    if source == Source::Stdin {
+        return Err(String::from(
+            "commands are read from stdin, use a file for --input",
+        ));
+    }
+    let input = source
+        .read(entry.day)
+        .map_err(|e| format!("cannot read input: {}", e))?;
+    let days: Vec<_> = step::DAYS
+        .iter()
+        .map(|entry| entry.day.to_string())
+        .collect();
+    let session = params::scope(&values, || {
+        step::session(entry.day, &input, io::stdin().lock(), io::stdout().lock())
+    });
+    match session {
+        None => Err(format!(
+            "day {} cannot be stepped through, only days {}",
+            entry.day,
+            days.join(", ")
+        )),
+        Some(Err(e)) => Err(e.diagnostic(&source.origin(entry.day))),
+        Some(Ok(result)) => result.map_err(|e| e.to_string()),
+    }
+}
+
+const DEFAULT_INTERVAL_MS: u64 = 500;
+
+/// Runs both parts on each source, checking the input of `set` against its
+/// recorded answers and printing the answers for the others.
+fn check_sources(entry: &Entry, set: &InputSet, sources: &[Source]) {
+    let answers = set
+        .read_answers()
+        .map_err(|e| format!("cannot read answers: {}", e))
+        .and_then(|answers| Answers::parse(&answers));
+    if let Err(e) = &answers {
+        println!("{}", e);
+    }
+    for source in sources {
+        let result = match solve(entry, source, &Part::ALL) {
+            Ok(result) => result,
+            Err(Failure::Read(e)) => {
+                println!("cannot read input: {}", e);
+                continue;
+            }
+            Err(Failure::Parse(e)) => {
+                print!("{}", e.diagnostic(&source.origin(entry.day)));
+                continue;
+            }
+        };
+        println!("{} ({:.3?})", source.origin(entry.day), result.time());
+        match &answers {
+            Ok(answers) if *source == Source::Set(set.clone()) => {
+                for (part, outcome) in answers::check(answers, &result) {
+                    print_outcome(entry.day, part, &outcome);
+                }
+            }
+            _ => {
+                for part in result.parts.iter() {
+                    let answer = part.answer.as_deref().unwrap_or("no solution");
+                    println!("day{:<3} {}  {}", entry.day, part.part, answer);
+                }
+            }
+        }
+    }
+}
+
+fn watch(args: &[String]) -> Result<(), String> {
+    let mut entry = None;
+    let mut alternate = None;
+    let mut set = InputSet::default();
+    let mut interval = DEFAULT_INTERVAL_MS;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or("missing value for --input")?;
+                alternate = Some(PathBuf::from(path));
+            }
+            "--set" => set = parse_set(args.next())?,
+            "--interval" => interval = parse_number("--interval", args.next())?,
+            arg => entry = Some(parse_day(arg)?),
+        }
+    }
+    let entry = entry.ok_or("no day selected")?;
+    let mut sources = vec![Source::Set(set.clone())];
+    let mut paths = vec![set.input_path(entry.day), set.answers_path()];
+    if let Some(path) = alternate {
+        sources.push(Source::File(path.clone()));
+        paths.push(path);
+    }
+    let mut watcher = Watcher::new(paths);
+    check_sources(entry, &set, &sources);
+    loop {
+        thread::sleep(Duration::from_millis(interval));
+        let changed = watcher.poll();
+        if changed.is_empty() {
+            continue;
+        }
+        for path in changed {
+            println!("\nchanged: {}", path.display());
+        }
+        check_sources(entry, &set, &sources);
+    }
+}
+
+fn check_input(args: &[String]) -> Result<(), String> {
+    let mut entry = None;
+    let mut source = Source::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or("missing value for --input")?;
+                source = Source::from_arg(path);
+            }
+            "--set" => source = Source::Set(parse_set(args.next())?),
+            arg => entry = Some(parse_day(arg)?),
+        }
+    }
+    let entry = entry.ok_or("no day selected")?;
+    let origin = source.origin(entry.day);
+    let raw = source
+        .read_raw(entry.day)
+        .map_err(|e| format!("cannot read input: {}", e))?;
+    for irregularity in input::irregularities(&raw) {
+        println!("warning: {} (normalized when read)", irregularity);
+    }
+    let errors = entry.check(&input::normalize(&raw));
+    for e in errors.iter() {
+        print!("{}", e.diagnostic(&origin));
+    }
+    if errors.is_empty() {
+        println!("{}: valid input for day {}", origin, entry.day);
+        Ok(())
+    } else {
+        Err(format!("{}: {} error(s) found", origin, errors.len()))
+    }
+}
+
+fn new_day(args: &[String]) -> Result<(), String> {
+    let [arg] = args else {
+        return Err(String::from("usage: aoc new <day>"));
+    };
+    let day = arg
+        .parse::<u32>()
+        .map_err(|_| format!("invalid day: {}", arg))?;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for path in scaffold::create(root, &input::input_path(day), day)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// The error for a command or option left out of this build.
+#[cfg(not(feature = "trace"))]
+fn without_feature(what: &str, feature: &str) -> String {
+    format!(
+        "{} needs aoc built with the {} feature (--features {})",
+        what, feature, feature
+    )
+}
+
+fn list() -> Result<(), String> {
+    for entry in runner::DAYS {
+        println!("day{}", entry.day);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("gen") => generate(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        Some("step") => step(&args[1..]),
+        Some("check-input") => check_input(&args[1..]),
+        Some("history") => show_history(&args[1..]),
+        Some("params") => show_params(&args[1..]),
+        Some("new") => new_day(&args[1..]),
+        Some("list") => list(),
+        Some(command) => Err(format!("unknown command: {}\n{}", command, USAGE)),
+        None => Err(String::from(USAGE)),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
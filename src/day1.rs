@@ -0,0 +1,133 @@
+#![cfg(feature = "week1")]
+
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::parse::{self, Template};
+use crate::simulation::{self, Simulation};
+use crate::Solution;
+
+/// Whether some frequency of the first pass is reached again by a later one,
+/// each pass being the first one shifted by `drift`.
+fn repeats(frequencies: &[i64], drift: i64) -> bool {
+    if drift == 0 {
+        return true;
+    }
+    let mut residues = HashSet::with_capacity(frequencies.len());
+    frequencies
+        .iter()
+        .any(|frequency| !residues.insert(frequency.rem_euclid(drift)))
+}
+
+/// The device applying the frequency changes over and over, until it reaches
+/// a frequency twice. From then on it stands still, so the run converges there.
+#[derive(Clone)]
+struct Device<'a> {
+    changes: &'a [i64],
+    index: usize,
+    frequency: i64,
+    seen: HashSet<i64>,
+    repeated: bool,
+}
+
+impl<'a> Device<'a> {
+    fn new(changes: &'a [i64]) -> Self {
+        Device {
+            changes,
+            index: 0,
+            frequency: 0,
+            seen: std::iter::once(0).collect(),
+            repeated: false,
+        }
+    }
+}
+
+impl Simulation for Device<'_> {
+    /// The running frequency: frequencies only come back once the device
+    /// stopped on the first repeated one.
+    type Key = (i64, bool);
+
+    fn step(&mut self) {
+        if self.repeated {
+            return;
+        }
+        self.frequency += self.changes[self.index];
+        self.index = (self.index + 1) % self.changes.len();
+        self.repeated = !self.seen.insert(self.frequency);
+    }
+
+    fn state_key(&self) -> (i64, bool) {
+        (self.frequency, self.repeated)
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let change = Template::new("{}");
+        parse::lines(Self::DAY, input, |cursor| change.parse(cursor))
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(input.iter().sum())
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let frequencies: Vec<i64> = input
+            .iter()
+            .scan(0, |frequency, change| {
+                *frequency += change;
+                Some(*frequency)
+            })
+            .collect();
+        // Each pass ends where the next one starts, shifted by the whole drift
+        let drift = *frequencies.last()?;
+        if !repeats(&frequencies, drift) {
+            return None;
+        }
+        let run = simulation::run(&Device::new(input), usize::MAX, usize::MAX)?;
+        Some(run.state.frequency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part1(input: &str) -> i64 {
+        Day1::part1(&Day1::parse(input).unwrap()).unwrap()
+    }
+
+    fn part2(input: &str) -> i64 {
+        Day1::part2(&Day1::parse(input).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(part1("+1\n-2\n+3\n+1"), 3);
+        assert_eq!(part1("+1\n+1\n+1"), 3);
+        assert_eq!(part1("+1\n+1\n-2"), 0);
+        assert_eq!(part1("-1\n-2\n-3"), -6);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(part2("+1\n-2\n+3\n+1"), 2);
+        assert_eq!(part2("+1\n-1"), 0);
+        assert_eq!(part2("+3\n+3\n+4\n-2\n-4"), 10);
+        assert_eq!(part2("-6\n+3\n+8\n+5\n-6"), 5);
+        assert_eq!(part2("+7\n+7\n-2\n-7\n-4"), 14);
+    }
+
+    #[test]
+    fn part2_never_repeats() {
+        assert_eq!(Day1::part2(&Day1::parse("+1\n+2").unwrap()), None);
+        assert_eq!(Day1::part2(&Day1::parse("+3\n-2").unwrap()), Some(3));
+    }
+}
@@ -0,0 +1,269 @@
+#![cfg(feature = "week2")]
+
+use crate::error::ParseError;
+use crate::json::Value;
+use crate::ocr;
+use crate::params::{Param, Parameter};
+use crate::parse::{self, Template};
+use crate::point::{Bounds, Point};
+use crate::step::Steppable;
+use crate::{Answer, Solution};
+
+fn height(positions: &[Point<i32>]) -> i32 {
+    Bounds::around(positions.iter().copied()).map_or(0, |bounds| bounds.extent().y)
+}
+
+/// The star positions at the moment they spell out a message.
+pub struct Message {
+    positions: Vec<Point<i32>>,
+}
+
+impl Message {
+    /// Lit pixels as rows, trimmed to the bounding box of the stars.
+    pub fn pixels(&self) -> Vec<Vec<bool>> {
+        let bounds = Bounds::around(self.positions.iter().copied()).unwrap();
+        let mut positions = self
+            .positions
+            .iter()
+            .map(|&p| p - bounds.min)
+            .collect::<Vec<_>>();
+        positions.sort_by_key(|p| (p.y, p.x));
+        let size = bounds.extent();
+        (0..=size.y)
+            .map(|y| {
+                (0..=size.x)
+                    .map(|x| {
+                        positions
+                            .binary_search_by_key(&(y, x), |p| (p.y, p.x))
+                            .is_ok()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn render(&self) -> String {
+        self.pixels()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&lit| if lit { '#' } else { ' ' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Answer for Message {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        let pixels = self.pixels();
+        vec![
+            ("width", pixels.first().map_or(0, Vec::len).into()),
+            ("height", pixels.len().into()),
+        ]
+    }
+}
+
+/// Prints the recognised letters, falling back to the rendered stars.
+impl std::fmt::Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match ocr::recognize(&self.pixels()) {
+            Some(text) => write!(f, "{}", text),
+            None => write!(f, "{}", self.render()),
+        }
+    }
+}
+
+const MAX_ITERATIONS: Param<usize> = Param::new(
+    10,
+    "max_iterations",
+    11000,
+    "Seconds the stars are moved waiting for them to converge",
+)
+.at_most(1_000_000);
+
+#[derive(Clone)]
+pub struct Stars {
+    pub positions: Vec<Point<i32>>,
+    pub velocities: Vec<Point<i32>>,
+}
+
+/// Largest field of stars drawn by `show`.
+#[cfg(feature = "visualize")]
+const SHOWN_SIZE: Point<i32> = Point::new(200, 40);
+
+/// The stars moving second by second.
+#[derive(Clone)]
+pub struct Sky {
+    pub stars: Stars,
+    pub time: usize,
+}
+
+impl Sky {
+    pub fn new(stars: Stars) -> Self {
+        Sky { stars, time: 0 }
+    }
+
+    fn size(&self) -> Point<i32> {
+        Bounds::around(self.stars.positions.iter().copied()).map_or(Point::default(), |bounds| {
+            bounds.extent() + Point::new(1, 1)
+        })
+    }
+}
+
+impl Steppable for Sky {
+    fn step(&mut self) -> bool {
+        let stars = &mut self.stars;
+        for (position, velocity) in stars.positions.iter_mut().zip(stars.velocities.iter()) {
+            *position += *velocity;
+        }
+        self.time += 1;
+        true
+    }
+
+    fn values(&self) -> Vec<(&'static str, i64)> {
+        let size = self.size();
+        vec![
+            ("time", self.time as i64),
+            ("width", size.x as i64),
+            ("height", size.y as i64),
+        ]
+    }
+
+    #[cfg(feature = "visualize")]
+    fn show(&self) -> String {
+        let size = self.size();
+        if size.x > SHOWN_SIZE.x || size.y > SHOWN_SIZE.y {
+            return format!("stars too spread out to draw ({}x{})", size.x, size.y);
+        }
+        Message {
+            positions: self.stars.positions.clone(),
+        }
+        .render()
+    }
+}
+
+/// Moves the stars until the height of the field stops shrinking, returning the
+/// iteration with the smallest height and the positions at that iteration.
+pub fn converge(stars: &Stars) -> Option<(usize, Vec<Point<i32>>)> {
+    let mut positions = stars.positions.clone();
+    let mut prev_positions = positions.clone();
+    let mut prev_height = height(&positions);
+    for iteration in 0..MAX_ITERATIONS.get() {
+        let height = height(&positions);
+        if height > prev_height {
+            return Some((iteration - 1, prev_positions));
+        }
+        prev_positions = positions.clone();
+        prev_height = height;
+        positions
+            .iter_mut()
+            .zip(stars.velocities.iter())
+            .for_each(|(position, velocity)| {
+                *position += *velocity;
+            });
+    }
+    None
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const PARAMS: &'static [&'static dyn Parameter] = &[&MAX_ITERATIONS];
+    type Input = Stars;
+    type Part1 = Message;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut positions = Vec::with_capacity(32);
+        let mut velocities = Vec::with_capacity(32);
+        let star = Template::new("position=<{},{}> velocity=<{},{}>");
+        parse::lines(Self::DAY, input, |cursor| {
+            let (x, y, dx, dy) = star.parse(cursor)?;
+            Ok((Point::new(x, y), Point::new(dx, dy)))
+        })?
+        .into_iter()
+        .for_each(|(position, velocity)| {
+            positions.push(position);
+            velocities.push(velocity);
+        });
+        if positions.is_empty() {
+            return Err(parse::end_of_input(Self::DAY, input, "`position=`"));
+        }
+        Ok(Stars {
+            positions,
+            velocities,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let (_, positions) = converge(input)?;
+        Some(Message { positions })
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let (iteration, _) = converge(input)?;
+        Some(iteration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>";
+
+    const MESSAGE: &str = "\
+#   #  ###
+#   #   # 
+#   #   # 
+#####   # 
+#   #   # 
+#   #   # 
+#   #   # 
+#   #  ###";
+
+    #[test]
+    fn part1_example() {
+        let message = Day10::part1(&Day10::parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(message.render(), MESSAGE);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day10::part2(&Day10::parse(EXAMPLE).unwrap()), Some(3));
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "week2")]
+
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::json::Value;
+use crate::params::{Param, Parameter};
+use crate::parse::{self, Template};
+use crate::point::Point;
+use crate::{Answer, Solution};
+
+const GRID_SIZE: Param<usize> = Param::new(
+    11,
+    "grid_size",
+    300,
+    "Width and height of the grid of fuel cells",
+)
+.at_least(1)
+.at_most(1000);
+const SQUARE_SIZE: Param<usize> = Param::new(
+    11,
+    "square_size",
+    3,
+    "Width and height of the squares compared in part 1",
+)
+.at_least(1)
+.at_most(1000);
+
+/// Top-left coordinate of a square of fuel cells, with its size when it is not fixed.
+pub struct Square {
+    pub x: usize,
+    pub y: usize,
+    pub size: Option<usize>,
+    /// Total power of the square.
+    pub power: i32,
+}
+
+impl std::fmt::Display for Square {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.size {
+            Some(size) => write!(f, "{},{},{}", self.x, self.y, size),
+            None => write!(f, "{},{}", self.x, self.y),
+        }
+    }
+}
+
+impl Answer for Square {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![("power", self.power.into())]
+    }
+}
+
+/// Power level of each fuel cell of a `size` square grid, with the 1-based
+/// cell `(x, y)` at `(x - 1, y - 1)`.
+fn power_grid(grid_serial: usize, size: usize) -> Grid<i32> {
+    Grid::from_fn(size, size, |Point { x, y }| {
+        let (x, y) = (x + 1, y + 1);
+        ((((((x + 10) * y) + grid_serial) * (x + 10)) / 100) % 10) as i32 - 5
+    })
+}
+
+/// Summed-area table of `grid`: the total power of the cells above and to the
+/// left of each position, with an extra row and column of zeros first.
+fn summed_area(grid: &Grid<i32>) -> Grid<i32> {
+    let mut sums = Grid::from_fn(grid.width() + 1, grid.height() + 1, |_| 0);
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            sums[(x + 1, y + 1)] =
+                grid[(x, y)] + sums[(x, y + 1)] + sums[(x + 1, y)] - sums[(x, y)];
+        }
+    }
+    sums
+}
+
+/// Total power of the `size` square with its top-left corner at `(x, y)`,
+/// from the summed-area table `sums`.
+fn square_power(sums: &Grid<i32>, (x, y): (usize, usize), size: usize) -> i32 {
+    sums[(x + size, y + size)] - sums[(x, y + size)] - sums[(x + size, y)] + sums[(x, y)]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const PARAMS: &'static [&'static dyn Parameter] = &[&GRID_SIZE, &SQUARE_SIZE];
+    type Input = usize;
+    type Part1 = Square;
+    type Part2 = Square;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::single_line(Self::DAY, input, |cursor| Template::new("{}").parse(cursor))
+    }
+
+    fn part1(grid_serial: &Self::Input) -> Option<Self::Part1> {
+        let size = GRID_SIZE.get();
+        let square_size = SQUARE_SIZE.get();
+        let sums = summed_area(&power_grid(*grid_serial, size));
+        let mut max_square = (0, 0, i32::MIN);
+        for y in 0..=size.checked_sub(square_size)? {
+            for x in 0..=size - square_size {
+                let power = square_power(&sums, (x, y), square_size);
+                if power > max_square.2 {
+                    max_square = (x + 1, y + 1, power);
+                }
+            }
+        }
+        Some(Square {
+            x: max_square.0,
+            y: max_square.1,
+            size: None,
+            power: max_square.2,
+        })
+    }
+
+    fn part2(grid_serial: &Self::Input) -> Option<Self::Part2> {
+        let size = GRID_SIZE.get();
+        let sums = summed_area(&power_grid(*grid_serial, size));
+        let mut max_square = (0, 0, 0, i32::MIN);
+        for square_size in 1..=size {
+            for y in 0..=size - square_size {
+                for x in 0..=size - square_size {
+                    let power = square_power(&sums, (x, y), square_size);
+                    if power > max_square.3 {
+                        max_square = (x + 1, y + 1, square_size, power);
+                    }
+                }
+            }
+        }
+        Some(Square {
+            x: max_square.0,
+            y: max_square.1,
+            size: Some(max_square.2),
+            power: max_square.3,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_grid_examples() {
+        for &(serial, x, y, power) in &[
+            (8, 3, 5, 4),
+            (57, 122, 79, -5),
+            (39, 217, 196, 0),
+            (71, 101, 153, 4),
+        ] {
+            assert_eq!(power_grid(serial, GRID_SIZE.get())[(x - 1, y - 1)], power);
+        }
+    }
+
+    #[test]
+    fn part1_examples() {
+        let square = Day11::part1(&18).unwrap();
+        assert_eq!(
+            (square.to_string(), square.power),
+            (String::from("33,45"), 29)
+        );
+        let square = Day11::part1(&42).unwrap();
+        assert_eq!(
+            (square.to_string(), square.power),
+            (String::from("21,61"), 30)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let square = Day11::part2(&18).unwrap();
+        assert_eq!(
+            (square.to_string(), square.power),
+            (String::from("90,269,16"), 113)
+        );
+    }
+}
@@ -0,0 +1,405 @@
+#![cfg(feature = "week2")]
+
+use crate::error::{LineError, ParseError};
+use crate::json::Value;
+use crate::params::{Param, Parameter};
+use crate::parse::{self, Cursor, Field, Template};
+use crate::simulation::{self, Outcome, Simulation};
+use crate::step::Steppable;
+use crate::{Answer, Solution};
+
+const RULE_LEFT: usize = 2;
+const RULE_RIGHT: usize = 2;
+const RULE_LENGTH: usize = RULE_LEFT + 1 + RULE_RIGHT;
+
+const GENERATIONS: Param<usize> = Param::new(
+    12,
+    "generations",
+    20,
+    "Generations the plants grow for in part 1",
+)
+.at_most(100_000);
+const MAX_ITER_GENERATIONS: Param<usize> = Param::new(
+    12,
+    "max_iter_generations",
+    500,
+    "Generations simulated in part 2 waiting for the pattern to repeat",
+)
+.at_most(100_000);
+const GENERATIONS_P2: Param<usize> = Param::new(
+    12,
+    "generations_p2",
+    50000000000,
+    "Generations the plants grow for in part 2",
+);
+
+pub type Rule = [bool; RULE_LENGTH];
+
+/// A single pot: `#` if it has a plant, `.` if not.
+struct Pot(bool);
+
+impl Field for Pot {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        let pot = cursor.char("`#` or `.`", |c| c == '#' || c == '.')?;
+        Ok(Pot(pot == '#'))
+    }
+}
+
+/// A row of pots of any length.
+struct Row(Vec<bool>);
+
+impl Field for Row {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        let mut row = vec![cursor.parse::<Pot>()?.0];
+        while cursor.peek().is_some_and(|c| !c.is_whitespace()) {
+            row.push(cursor.parse::<Pot>()?.0);
+        }
+        Ok(Row(row))
+    }
+}
+
+impl Field for Rule {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        let mut rule = [false; RULE_LENGTH];
+        for e in rule.iter_mut() {
+            *e = cursor.parse::<Pot>()?.0;
+        }
+        Ok(rule)
+    }
+}
+
+fn matches_rule(state: &[(i64, bool)], rules: &[Rule]) -> bool {
+    assert_eq!(state.len(), RULE_LENGTH);
+    rules
+        .iter()
+        .any(|rule| rule.iter().zip(state).all(|(v1, (_, v2))| *v1 == *v2))
+}
+
+pub fn next_generation(state: &mut [(i64, bool)], rules: &[Rule]) {
+    const WINDOW_SIZE: usize = RULE_LEFT + 1;
+    let mut next_values = [false; WINDOW_SIZE];
+    for index in RULE_LEFT..RULE_LEFT + WINDOW_SIZE {
+        let next_value = &mut next_values[index % WINDOW_SIZE];
+        *next_value = matches_rule(&state[index - RULE_LEFT..=index + RULE_RIGHT], rules);
+    }
+    for index in RULE_LEFT + WINDOW_SIZE..state.len() - 1 - RULE_RIGHT {
+        let next_value = &mut next_values[index % WINDOW_SIZE];
+        state[index - WINDOW_SIZE].1 = *next_value;
+        *next_value = matches_rule(&state[index - RULE_LEFT..=index + RULE_RIGHT], rules);
+    }
+}
+
+pub struct Pots {
+    pub initial_state: Vec<bool>,
+    /// Only the rules that result in a plant.
+    pub rules: Vec<Rule>,
+}
+
+impl Pots {
+    /// Initial state padded with enough empty pots to run `generations` generations.
+    fn expanded_state(&self, generations: usize) -> Vec<(i64, bool)> {
+        let max_left_expansion = generations * RULE_RIGHT;
+        let max_right_expansion = generations * RULE_LEFT;
+        let state_len = self.initial_state.len();
+        let left =
+            (0..max_left_expansion).map(|index| (index as i64 - max_left_expansion as i64, false));
+        let state = self
+            .initial_state
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| (index as i64, value));
+        let right = (0..max_right_expansion).map(|index| (index as i64 + state_len as i64, false));
+        left.chain(state).chain(right).collect()
+    }
+}
+
+/// Empty pots kept on each side of the plants while stepping, enough for
+/// `next_generation` to grow plants next to the outermost ones.
+const GARDEN_MARGIN: i64 = 8;
+
+/// The pots growing generation by generation.
+#[derive(Clone)]
+pub struct Garden {
+    pub rules: Vec<Rule>,
+    pub state: Vec<(i64, bool)>,
+    pub generation: usize,
+}
+
+impl Garden {
+    pub fn new(pots: &Pots) -> Self {
+        let mut garden = Garden {
+            rules: pots.rules.clone(),
+            state: pots.expanded_state(0),
+            generation: 0,
+        };
+        garden.pad();
+        garden
+    }
+
+    fn plants(&self) -> Vec<i64> {
+        self.state
+            .iter()
+            .filter(|(_, plant)| *plant)
+            .map(|(pot, _)| *pot)
+            .collect()
+    }
+
+    /// Grows the next generation.
+    pub fn grow(&mut self) {
+        next_generation(&mut self.state, &self.rules);
+        self.generation += 1;
+        self.pad();
+    }
+
+    /// Keeps exactly `GARDEN_MARGIN` empty pots on each side of the plants.
+    fn pad(&mut self) {
+        let plants = self.plants();
+        let start = self.state.first().map_or(0, |&(pot, _)| pot);
+        let first = plants.first().copied().unwrap_or(start);
+        let last = plants.last().copied().unwrap_or(start);
+        self.state = (first - GARDEN_MARGIN..=last + GARDEN_MARGIN)
+            .map(|pot| (pot, plants.binary_search(&pot).is_ok()))
+            .collect();
+    }
+}
+
+/// The pots from the first one shown, `#` for the ones with a plant.
+#[cfg(feature = "visualize")]
+impl std::fmt::Display for Garden {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let first = self.state.first().map_or(0, |&(pot, _)| pot);
+        write!(f, "{:>4}: ", first)?;
+        for &(_, plant) in self.state.iter() {
+            write!(f, "{}", if plant { '#' } else { '.' })?;
+        }
+        Ok(())
+    }
+}
+
+impl Steppable for Garden {
+    fn step(&mut self) -> bool {
+        self.grow();
+        true
+    }
+
+    fn values(&self) -> Vec<(&'static str, i64)> {
+        let plants = self.plants();
+        vec![
+            ("generation", self.generation as i64),
+            ("plants", plants.len() as i64),
+            ("sum", plants.iter().sum()),
+        ]
+    }
+
+    #[cfg(feature = "visualize")]
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Gardens whose plants only moved share a key, so that the drift of a settled
+/// pattern counts as converging.
+impl Simulation for Garden {
+    type Key = Vec<bool>;
+
+    fn step(&mut self) {
+        self.grow();
+    }
+
+    fn state_key(&self) -> Vec<bool> {
+        self.state.iter().map(|&(_, plant)| plant).collect()
+    }
+
+    /// Numbers the pots from the first one shown.
+    fn normalize(&mut self) -> i64 {
+        let first = self.state.first().map_or(0, |&(pot, _)| pot);
+        for (pot, _) in self.state.iter_mut() {
+            *pot -= first;
+        }
+        first
+    }
+}
+
+/// Sum of the pots with plants after the last generation, extrapolated from the
+/// generation where the pattern stops changing and only shifts by `offset` pots.
+pub struct Extrapolated {
+    pub sum: i64,
+    pub generation: usize,
+    pub offset: i64,
+}
+
+impl std::fmt::Display for Extrapolated {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.sum)
+    }
+}
+
+impl Answer for Extrapolated {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("converged_generation", self.generation.into()),
+            ("offset", self.offset.into()),
+        ]
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const PARAMS: &'static [&'static dyn Parameter] =
+        &[&GENERATIONS, &GENERATIONS_P2, &MAX_ITER_GENERATIONS];
+    type Input = Pots;
+    type Part1 = i64;
+    type Part2 = Extrapolated;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+        let mut next_line = |expected| {
+            lines
+                .next()
+                .ok_or_else(|| parse::end_of_input(Self::DAY, input, expected))
+        };
+        // Read initial state
+        let (index, line) = next_line("`initial state: `")?;
+        let initial_state = Template::new("initial state: {}")
+            .parse::<Row>(&mut Cursor::new(line))
+            .map_err(|e| parse::report(e.at(Self::DAY, index, line)));
+        // Skip empty line
+        let (index, line) = next_line("empty line")?;
+        let empty = Template::new("")
+            .parse::<()>(&mut Cursor::new(line))
+            .map_err(|e| parse::report(e.at(Self::DAY, index, line)));
+        // Read rules, store only the rules that result in a plant, checking
+        // them even after a bad header to report every error
+        let rule = Template::new("{} => {}");
+        let rules =
+            parse::numbered_lines(Self::DAY, lines, |cursor| rule.parse::<(Rule, Pot)>(cursor));
+        let Row(initial_state) = initial_state?;
+        empty?;
+        let rules = rules?
+            .into_iter()
+            .filter(|(_, Pot(result))| *result)
+            .map(|(rule, _)| rule)
+            .collect::<Vec<_>>();
+        Ok(Pots {
+            initial_state,
+            rules,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let generations = GENERATIONS.get();
+        let mut state = input.expanded_state(generations);
+        // Iterate over generations
+        for _ in 0..generations {
+            next_generation(&mut state, &input.rules);
+        }
+        // Calculate the sum of pot numbers with plants
+        Some(
+            state
+                .iter()
+                .filter(|(_, plant)| *plant)
+                .map(|(num, _)| num)
+                .sum(),
+        )
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let garden = Garden::new(input);
+        let generations = GENERATIONS_P2.get();
+        let run = simulation::run(&garden, generations, MAX_ITER_GENERATIONS.get())?;
+        let (generation, offset) = match run.outcome {
+            Outcome::Converged { at, shift } => (at, shift),
+            Outcome::Cycled { start, shift, .. } => (start, shift),
+            Outcome::Diverged => (generations, 0),
+        };
+        // Calculate the sum of pot numbers with plants, moved back into place
+        let plants = run.state.plants();
+        let sum = plants.iter().sum::<i64>() + plants.len() as i64 * run.translation;
+        Some(Extrapolated {
+            sum,
+            generation,
+            offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #";
+
+    fn plants(state: &[(i64, bool)]) -> Vec<i64> {
+        state
+            .iter()
+            .filter(|(_, plant)| *plant)
+            .map(|(pot, _)| *pot)
+            .collect()
+    }
+
+    #[test]
+    fn next_generation_example() {
+        let pots = Day12::parse(EXAMPLE).unwrap();
+        let mut state = pots.expanded_state(GENERATIONS.get());
+        next_generation(&mut state, &pots.rules);
+        assert_eq!(plants(&state), vec![0, 4, 9, 15, 18, 21, 24]);
+        next_generation(&mut state, &pots.rules);
+        assert_eq!(plants(&state), vec![0, 1, 4, 5, 9, 10, 15, 18, 21, 24, 25]);
+    }
+
+    #[test]
+    fn garden_example() {
+        let mut garden = Garden::new(&Day12::parse(EXAMPLE).unwrap());
+        (0..GENERATIONS.get()).for_each(|_| garden.grow());
+        assert_eq!(garden.plants().iter().sum::<i64>(), 325);
+    }
+
+    #[test]
+    #[cfg(feature = "visualize")]
+    fn garden_display() {
+        let mut garden = Garden::new(&Day12::parse(EXAMPLE).unwrap());
+        (0..GENERATIONS.get()).for_each(|_| garden.grow());
+        assert!(garden
+            .to_string()
+            .starts_with(" -10: ........#....##....#####"));
+    }
+
+    #[test]
+    fn all_errors_after_bad_header() {
+        let input = "initial state: #.x\n\n..#.. => #\n..#.x => .\n#.... => ?";
+        let errors = parse::all_errors(|| Day12::parse(input));
+        let lines: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(lines, vec![(1, 18), (4, 5), (5, 10)]);
+        assert_eq!(Day12::parse(input).err(), Some(errors[0].clone()));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day12::part1(&Day12::parse(EXAMPLE).unwrap()), Some(325));
+    }
+
+    #[test]
+    fn part2_example() {
+        let extrapolated = Day12::part2(&Day12::parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(extrapolated.offset, 1);
+        assert_eq!(extrapolated.sum, 999999999374);
+    }
+}
@@ -0,0 +1,753 @@
+#![cfg(feature = "week2")]
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+use crate::error::{LineError, ParseError};
+use crate::grid::Grid;
+use crate::json::Value;
+use crate::parse;
+use crate::point::Point;
+use crate::simulation::{self, Simulation};
+use crate::step::Steppable;
+use crate::trace;
+use crate::{Answer, Solution};
+
+pub use crate::point::Direction;
+
+pub type Position = Point<usize>;
+
+/// Ticks simulated before giving up on the carts ever repeating their moves.
+const MAX_TICKS: usize = 1_000_000;
+
+/// The track under each position of the map, if any.
+pub type Tracks = Grid<Option<Connections>>;
+
+#[derive(Debug)]
+pub struct Connections(u8);
+
+macro_rules! connections {
+    ( $( $x:expr ),* ) => {
+        {
+            let mut c = Connections::new();
+            $(
+                c.add_direction($x);
+            )*
+            c
+        }
+    };
+}
+
+impl PartialEq for Connections {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Connections {
+    fn new() -> Self {
+        Connections(0)
+    }
+    fn bit(direction: Direction) -> u8 {
+        match direction {
+            Direction::Up => 1 << 0,
+            Direction::Down => 1 << 1,
+            Direction::Left => 1 << 2,
+            Direction::Right => 1 << 3,
+        }
+    }
+    fn add_direction(&mut self, direction: Direction) {
+        self.0 |= Self::bit(direction);
+    }
+    pub fn has_direction(&self, direction: Direction) -> bool {
+        self.0 & Self::bit(direction) != 0
+    }
+    fn is_crossroad(&self) -> bool {
+        *self
+            == connections![
+                Direction::Up,
+                Direction::Down,
+                Direction::Right,
+                Direction::Left
+            ]
+    }
+    #[cfg(feature = "visualize")]
+    fn draw(&self) -> char {
+        if self.is_crossroad() {
+            '+'
+        } else if *self == connections![Direction::Up, Direction::Down] {
+            '|'
+        } else if *self == connections![Direction::Left, Direction::Right] {
+            '-'
+        } else if *self == connections![Direction::Up, Direction::Right]
+            || *self == connections![Direction::Down, Direction::Left]
+        {
+            '\\'
+        } else if *self == connections![Direction::Up, Direction::Left]
+            || *self == connections![Direction::Down, Direction::Right]
+        {
+            '/'
+        } else {
+            unreachable!("connections of no track: {:?}", self)
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Crossroad {
+    Left,
+    Straight,
+    Right,
+}
+
+#[derive(Clone, Copy)]
+pub struct Cart {
+    direction: Direction,
+    position: Position,
+    next_crossroad: Crossroad,
+}
+
+impl Ord for Cart {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match other.position.y.cmp(&self.position.y) {
+            Ordering::Equal => other.position.x.cmp(&self.position.x),
+            other => other,
+        }
+    }
+}
+
+impl PartialOrd for Cart {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Cart {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position
+    }
+}
+
+impl Eq for Cart {}
+
+impl Cart {
+    fn new(position: Position, direction: Direction) -> Self {
+        Cart {
+            direction,
+            position,
+            next_crossroad: Crossroad::Right,
+        }
+    }
+    fn next_crossroad(&mut self) -> Crossroad {
+        self.next_crossroad = match self.next_crossroad {
+            Crossroad::Left => Crossroad::Straight,
+            Crossroad::Straight => Crossroad::Right,
+            Crossroad::Right => Crossroad::Left,
+        };
+        self.next_crossroad
+    }
+    fn trace_move(&self, tick: usize) {
+        trace!(
+            "move",
+            tick = tick,
+            x = self.position.x,
+            y = self.position.y,
+            direction = self.direction.arrow(),
+        );
+    }
+    fn next_step(&mut self, map: &Tracks) {
+        self.position = self.position.step(self.direction);
+        // parse_map checked that every track leads to one connecting back
+        let track = track_at(map, self.position)
+            .unwrap_or_else(|| unreachable!("cart out of tracks: {:?}", self.position));
+        self.direction = if track.is_crossroad() {
+            match self.next_crossroad() {
+                Crossroad::Straight => self.direction,
+                Crossroad::Left => self.direction.turn_left(),
+                Crossroad::Right => self.direction.turn_right(),
+            }
+        } else {
+            let mut direction = None;
+            for &d in &[
+                self.direction,
+                self.direction.turn_left(),
+                self.direction.turn_right(),
+            ] {
+                if track.has_direction(d) {
+                    direction = Some(d);
+                    break;
+                }
+            }
+            direction.unwrap_or_else(|| unreachable!("track without a way out"))
+        }
+    }
+}
+
+/// Where the carts are and where they will go, from the top row down.
+fn carts_key(carts: &BinaryHeap<Cart>) -> Vec<(Position, Direction, Crossroad)> {
+    let mut key: Vec<_> = carts
+        .iter()
+        .map(|cart| (cart.position, cart.direction, cart.next_crossroad))
+        .collect();
+    key.sort_by_key(|(position, _, _)| (position.y, position.x));
+    key
+}
+
+fn track_at(tracks: &Tracks, position: Position) -> Option<&Connections> {
+    tracks.get(position).and_then(Option::as_ref)
+}
+
+pub fn parse_map(map: &str) -> Result<(Tracks, BinaryHeap<Cart>), ParseError> {
+    let chars = Grid::parse(Day13::DAY, map, |c| match c {
+        '-' | '|' | '/' | '\\' | '+' | '^' | 'v' | '<' | '>' => Ok(c),
+        _ if c.is_whitespace() => Ok(' '),
+        _ => Err("track or cart"),
+    })?;
+    let mut tracks: Tracks = Grid::from_fn(chars.width(), chars.height(), |_| None);
+    let mut carts = BinaryHeap::new();
+    // Whether the track next to `position` in `direction` leads back to it
+    let is_connected = |position: Position, direction: Direction, tracks: &Tracks| {
+        position
+            .checked_step(direction)
+            .and_then(|next| track_at(tracks, next))
+            .is_some_and(|track| track.has_direction(direction.reverse()))
+    };
+    // First pass
+    for (position, &c) in chars.iter() {
+        let mut connections = None;
+        let mut cart = None;
+        match c {
+            '-' => {
+                connections = Some(connections![Direction::Left, Direction::Right]);
+            }
+            '|' => {
+                connections = Some(connections![Direction::Up, Direction::Down]);
+            }
+            '/' => {
+                connections = if !is_connected(position, Direction::Left, &tracks) {
+                    Some(connections![Direction::Down, Direction::Right])
+                } else {
+                    Some(connections![Direction::Up, Direction::Left])
+                };
+            }
+            '\\' => {
+                connections = if !is_connected(position, Direction::Left, &tracks) {
+                    Some(connections![Direction::Up, Direction::Right])
+                } else {
+                    Some(connections![Direction::Down, Direction::Left])
+                };
+            }
+            '+' => {
+                connections = Some(connections![
+                    Direction::Up,
+                    Direction::Down,
+                    Direction::Right,
+                    Direction::Left
+                ]);
+            }
+            '^' => {
+                // Assume a straight track so neighbouring curves connect to it,
+                // figure out straight, crossroad or curve later
+                connections = Some(connections![Direction::Up, Direction::Down]);
+                cart = Some(Direction::Up);
+            }
+            'v' => {
+                // Assume a straight track so neighbouring curves connect to it,
+                // figure out straight, crossroad or curve later
+                connections = Some(connections![Direction::Up, Direction::Down]);
+                cart = Some(Direction::Down);
+            }
+            '<' => {
+                // Assume a straight track so neighbouring curves connect to it,
+                // figure out straight, crossroad or curve later
+                connections = Some(connections![Direction::Left, Direction::Right]);
+                cart = Some(Direction::Left);
+            }
+            '>' => {
+                // Assume a straight track so neighbouring curves connect to it,
+                // figure out straight, crossroad or curve later
+                connections = Some(connections![Direction::Left, Direction::Right]);
+                cart = Some(Direction::Right);
+            }
+            _ => {}
+        }
+        if let Some(connections) = connections {
+            tracks[position] = Some(connections);
+        }
+        if let Some(direction) = cart {
+            carts.push(Cart::new(position, direction));
+        }
+    }
+    // Second pass, figure out straight, crossroad or curve for positions with carts
+    for cart in carts.iter() {
+        let connections = match cart.direction {
+            Direction::Up => {
+                match (
+                    is_connected(cart.position, Direction::Left, &tracks),
+                    is_connected(cart.position, Direction::Right, &tracks),
+                ) {
+                    (true, true) => connections![
+                        Direction::Up,
+                        Direction::Down,
+                        Direction::Right,
+                        Direction::Left
+                    ],
+                    (true, false) => connections![Direction::Up, Direction::Left],
+                    (false, true) => connections![Direction::Up, Direction::Right],
+                    _ => connections![Direction::Up, Direction::Down],
+                }
+            }
+            Direction::Down => {
+                match (
+                    is_connected(cart.position, Direction::Left, &tracks),
+                    is_connected(cart.position, Direction::Right, &tracks),
+                ) {
+                    (true, true) => connections![
+                        Direction::Up,
+                        Direction::Down,
+                        Direction::Right,
+                        Direction::Left
+                    ],
+                    (true, false) => connections![Direction::Down, Direction::Left],
+                    (false, true) => connections![Direction::Down, Direction::Right],
+                    _ => connections![Direction::Down, Direction::Up],
+                }
+            }
+            Direction::Left => {
+                match (
+                    is_connected(cart.position, Direction::Up, &tracks),
+                    is_connected(cart.position, Direction::Down, &tracks),
+                ) {
+                    (true, true) => connections![
+                        Direction::Up,
+                        Direction::Down,
+                        Direction::Right,
+                        Direction::Left
+                    ],
+                    (true, false) => connections![Direction::Left, Direction::Up],
+                    (false, true) => connections![Direction::Left, Direction::Down],
+                    _ => connections![Direction::Left, Direction::Right],
+                }
+            }
+            Direction::Right => {
+                match (
+                    is_connected(cart.position, Direction::Up, &tracks),
+                    is_connected(cart.position, Direction::Down, &tracks),
+                ) {
+                    (true, true) => connections![
+                        Direction::Up,
+                        Direction::Down,
+                        Direction::Right,
+                        Direction::Left
+                    ],
+                    (true, false) => connections![Direction::Right, Direction::Up],
+                    (false, true) => connections![Direction::Right, Direction::Down],
+                    _ => connections![Direction::Right, Direction::Left],
+                }
+            }
+        };
+        tracks[cart.position] = Some(connections);
+    }
+    // Third pass, check that every track leads to one connecting back, so
+    // carts never run off the tracks
+    let mut first = None;
+    let mut bad_line = None;
+    for (position, track) in tracks.iter() {
+        let Some(track) = track else { continue };
+        let broken = Direction::ALL.iter().copied().find(|&direction| {
+            track.has_direction(direction) && !is_connected(position, direction, &tracks)
+        });
+        let Some(direction) = broken.filter(|_| bad_line != Some(position.y)) else {
+            continue;
+        };
+        let side = match direction {
+            Direction::Up => "above",
+            Direction::Down => "below",
+            Direction::Left => "to the left",
+            Direction::Right => "to the right",
+        };
+        let line = map.lines().nth(position.y).unwrap_or("");
+        let e = parse::report(
+            LineError::new(
+                position.x + 1,
+                format!("track continuing {}", side),
+                format!("`{}`", chars[position]),
+            )
+            .at(Day13::DAY, position.y, line),
+        );
+        if !parse::collecting() {
+            return Err(e);
+        }
+        // One error per line is enough
+        first.get_or_insert(e);
+        bad_line = Some(position.y);
+    }
+    match first {
+        Some(e) => Err(e),
+        None => Ok((tracks, carts)),
+    }
+}
+
+/// Position of a cart, printed as `x,y`.
+pub struct Location {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Answer for Location {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![("x", self.x.into()), ("y", self.y.into())]
+    }
+}
+
+impl From<Position> for Location {
+    fn from(position: Position) -> Self {
+        Location {
+            x: position.x,
+            y: position.y,
+        }
+    }
+}
+
+/// The last cart left, after the crashes that removed the others.
+pub struct LastCart {
+    pub location: Location,
+    /// Crash sites with the tick they happened in, in order.
+    pub crashes: Vec<(usize, Location)>,
+}
+
+impl std::fmt::Display for LastCart {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.location.fmt(f)
+    }
+}
+
+impl Answer for LastCart {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        let mut values = self.location.values();
+        values.push(("crashes", self.crashes.len().into()));
+        values
+    }
+
+    fn explain(&self) -> Vec<String> {
+        let mut lines: Vec<_> = self
+            .crashes
+            .iter()
+            .map(|(tick, location)| format!("tick {}: carts crash at {}", tick, location))
+            .collect();
+        lines.push(format!("the last cart is at {}", self.location));
+        lines
+    }
+}
+
+pub struct Mine {
+    pub tracks: Tracks,
+    pub carts: BinaryHeap<Cart>,
+}
+
+/// Carts moving tick by tick, removed as soon as they crash.
+#[derive(Clone)]
+pub struct Traffic<'a> {
+    pub tracks: &'a Tracks,
+    pub carts: BinaryHeap<Cart>,
+    pub tick: usize,
+    /// Crash sites with the tick they happened in, in order.
+    pub crashes: Vec<(usize, Position)>,
+}
+
+impl<'a> Traffic<'a> {
+    pub fn new(mine: &'a Mine) -> Self {
+        Traffic {
+            tracks: &mine.tracks,
+            carts: mine.carts.clone(),
+            tick: 0,
+            crashes: Vec::new(),
+        }
+    }
+
+    /// Moves every cart once, from the top row down, removing the carts that crash.
+    pub fn tick(&mut self) {
+        let mut carts_next = Vec::with_capacity(self.carts.len());
+        let mut to_remove = HashSet::with_capacity(16);
+        self.tick += 1;
+        while let Some(mut cart) = self.carts.pop() {
+            if to_remove.remove(&cart.position) {
+                continue;
+            }
+            cart.next_step(self.tracks);
+            cart.trace_move(self.tick);
+            let mut removed = false;
+            for other in self.carts.iter() {
+                if &cart == other {
+                    to_remove.insert(other.position);
+                    removed = true;
+                    break;
+                }
+            }
+            for i in 0..carts_next.len() {
+                if cart == carts_next[i] {
+                    carts_next.remove(i);
+                    removed = true;
+                    break;
+                }
+            }
+            if removed {
+                trace!(
+                    "crash",
+                    tick = self.tick,
+                    x = cart.position.x,
+                    y = cart.position.y,
+                );
+                self.crashes.push((self.tick, cart.position));
+            } else {
+                carts_next.push(cart);
+            }
+        }
+        self.carts.extend(carts_next);
+    }
+}
+
+/// The tracks with the carts on them, and crashes of the last tick as `X`.
+#[cfg(feature = "visualize")]
+impl std::fmt::Display for Traffic<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let tracks = self.tracks;
+        let mut picture = Grid::from_fn(tracks.width(), tracks.height(), |position| {
+            track_at(tracks, position).map_or(' ', Connections::draw)
+        });
+        for cart in self.carts.iter() {
+            picture[cart.position] = cart.direction.arrow();
+        }
+        for &(_, position) in self.crashes.iter().filter(|(tick, _)| *tick == self.tick) {
+            picture[position] = 'X';
+        }
+        write!(f, "{}", picture.display(|&c| c))
+    }
+}
+
+impl Steppable for Traffic<'_> {
+    fn step(&mut self) -> bool {
+        if self.carts.len() < 2 {
+            return false;
+        }
+        self.tick();
+        true
+    }
+
+    fn values(&self) -> Vec<(&'static str, i64)> {
+        vec![
+            ("tick", self.tick as i64),
+            ("carts", self.carts.len() as i64),
+            ("crashes", self.crashes.len() as i64),
+        ]
+    }
+
+    #[cfg(feature = "visualize")]
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Traffic stands still once at most one cart is left.
+impl Simulation for Traffic<'_> {
+    type Key = (Vec<(Position, Direction, Crossroad)>, usize);
+
+    fn step(&mut self) {
+        if self.carts.len() >= 2 {
+            self.tick();
+        }
+    }
+
+    fn state_key(&self) -> Self::Key {
+        (carts_key(&self.carts), self.crashes.len())
+    }
+}
+
+/// Moves the carts until at most one is left, removing carts as soon as they
+/// crash and reporting each crash tick and site to `crashed`. Returns the last
+/// cart, or `None` if the carts left end up going round forever without crashing.
+pub fn remove_crashes(mine: &Mine, mut crashed: impl FnMut(usize, Position)) -> Option<Position> {
+    let traffic = simulation::run(&Traffic::new(mine), usize::MAX, MAX_TICKS)?.state;
+    for &(tick, position) in traffic.crashes.iter() {
+        crashed(tick, position);
+    }
+    match traffic.carts.len() {
+        0 | 1 => traffic.carts.peek().map(|cart| cart.position),
+        _ => None,
+    }
+}
+
+/// Carts moving without being removed, until the first crash stops them all.
+#[derive(Clone)]
+struct Collisions<'a> {
+    tracks: &'a Tracks,
+    carts: BinaryHeap<Cart>,
+    tick: usize,
+    crash: Option<Position>,
+}
+
+impl Simulation for Collisions<'_> {
+    type Key = (Vec<(Position, Direction, Crossroad)>, Option<Position>);
+
+    fn step(&mut self) {
+        if self.crash.is_some() {
+            return;
+        }
+        let mut carts_next = BinaryHeap::with_capacity(self.carts.len());
+        self.tick += 1;
+        while let Some(mut cart) = self.carts.pop() {
+            cart.next_step(self.tracks);
+            cart.trace_move(self.tick);
+            for other in self.carts.iter().chain(carts_next.iter()) {
+                if &cart == other {
+                    trace!(
+                        "crash",
+                        tick = self.tick,
+                        x = cart.position.x,
+                        y = cart.position.y,
+                    );
+                    self.crash = Some(cart.position);
+                    return;
+                }
+            }
+            carts_next.push(cart);
+        }
+        self.carts = carts_next;
+    }
+
+    fn state_key(&self) -> Self::Key {
+        (carts_key(&self.carts), self.crash)
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Mine;
+    type Part1 = Location;
+    type Part2 = LastCart;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (tracks, carts) = parse_map(input)?;
+        Ok(Mine { tracks, carts })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        if input.carts.len() < 2 {
+            return None;
+        }
+        let collisions = Collisions {
+            tracks: &input.tracks,
+            carts: input.carts.clone(),
+            tick: 0,
+            crash: None,
+        };
+        let run = simulation::run(&collisions, usize::MAX, MAX_TICKS)?;
+        run.state.crash.map(Location::from)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let mut crashes = Vec::new();
+        let last = remove_crashes(input, |tick, position| {
+            crashes.push((tick, Location::from(position)));
+        })?;
+        Some(LastCart {
+            location: last.into(),
+            crashes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLLISION: &str = r"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/";
+
+    const LAST_CART: &str = r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/";
+
+    #[test]
+    fn parse_map_example() {
+        let (tracks, carts) = parse_map(COLLISION).unwrap();
+        assert_eq!(carts.len(), 2);
+        // Carts stand on tracks too
+        assert!(carts.iter().all(|cart| tracks[cart.position].is_some()));
+        assert!(parse_map("/-x-\\").is_err());
+    }
+
+    #[test]
+    fn parse_map_broken_track() {
+        let e = parse_map("->- ->-").err().unwrap();
+        assert_eq!((e.line, e.column), (1, 1));
+        assert_eq!(e.expected, "track continuing to the left");
+        assert_eq!(e.found, "`-`");
+        let e = parse_map("/-\\\n| |\n\\-\\").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 3, "`|`"));
+        let errors = parse::all_errors(|| parse_map("->-\n/-\\\n|\n\\-/"));
+        assert_eq!(
+            errors.iter().map(|e| e.line).collect::<Vec<_>>(),
+            vec![1, 2, 4]
+        );
+    }
+
+    #[test]
+    fn parse_map_cart_next_to_curve() {
+        let (tracks, _) = parse_map("/<\\\n| ^\n\\-/").unwrap();
+        assert!(track_at(&tracks, Point::new(2, 0))
+            .unwrap()
+            .has_direction(Direction::Left));
+        assert!(track_at(&tracks, Point::new(2, 0))
+            .unwrap()
+            .has_direction(Direction::Down));
+        assert!(!track_at(&tracks, Point::new(1, 0))
+            .unwrap()
+            .has_direction(Direction::Up));
+    }
+
+    #[test]
+    fn part1_example() {
+        let crash = Day13::part1(&Day13::parse(COLLISION).unwrap()).unwrap();
+        assert_eq!(crash.to_string(), "7,3");
+    }
+
+    #[test]
+    fn part2_example() {
+        let last = Day13::part2(&Day13::parse(LAST_CART).unwrap()).unwrap();
+        assert_eq!(last.to_string(), "6,4");
+        assert_eq!(
+            last.explain(),
+            vec![
+                "tick 1: carts crash at 2,0",
+                "tick 1: carts crash at 2,4",
+                "tick 1: carts crash at 6,4",
+                "tick 3: carts crash at 2,4",
+                "the last cart is at 6,4",
+            ]
+        );
+    }
+
+    #[test]
+    fn carts_that_never_crash() {
+        let mine = Day13::parse("/->\\ /-<\\\n|  | |  |\n\\--/ \\--/").unwrap();
+        assert!(Day13::part1(&mine).is_none());
+        assert!(Day13::part2(&mine).is_none());
+    }
+}
@@ -0,0 +1,189 @@
+#![cfg(all(feature = "week2", feature = "heavy"))]
+
+use std::collections::HashSet;
+
+use crate::error::{LineError, ParseError};
+use crate::params::{Param, Parameter};
+use crate::parse::{self, Cursor, Field, Template};
+use crate::Solution;
+
+fn digit(ascii: u8) -> u8 {
+    ascii - b'0'
+}
+
+pub struct Matcher<'a, T>
+where
+    T: std::cmp::PartialEq,
+{
+    needle: &'a [T],
+    count: usize,
+    matches: Vec<usize>,
+    ongoing: HashSet<usize>,
+    ongoing_remove: HashSet<usize>,
+}
+
+impl<'a, T> Matcher<'a, T>
+where
+    T: std::cmp::PartialEq,
+{
+    pub fn new(needle: &'a [T]) -> Self {
+        Self {
+            needle,
+            count: 0,
+            matches: vec![],
+            ongoing: HashSet::with_capacity(needle.len()),
+            ongoing_remove: HashSet::with_capacity(needle.len()),
+        }
+    }
+    pub fn consume(&mut self, item: &T) {
+        for &start in self.ongoing.iter() {
+            if item != &self.needle[self.count - start] {
+                self.ongoing_remove.insert(start);
+            }
+        }
+        for start in self.ongoing_remove.iter() {
+            self.ongoing.remove(start);
+        }
+        self.ongoing_remove.clear();
+        if item == &self.needle[0] {
+            self.ongoing.insert(self.count);
+        }
+        self.count += 1;
+        for &start in self.ongoing.iter() {
+            if self.count - start == self.needle.len() {
+                self.matches.push(start);
+                self.ongoing_remove.insert(start);
+            }
+        }
+        for start in self.ongoing_remove.iter() {
+            self.ongoing.remove(start);
+        }
+        self.ongoing_remove.clear();
+    }
+    pub fn consume_all(&mut self, iter: impl Iterator<Item = T>) {
+        iter.for_each(|item| self.consume(&item));
+    }
+    pub fn get_matches(&self) -> &[usize] {
+        &self.matches
+    }
+}
+
+/// The puzzle number, kept as digits since leading zeros matter when searching for it.
+struct Digits(String);
+
+impl Field for Digits {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        let digits: String = cursor
+            .rest()
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        if digits.is_empty() {
+            return Err(cursor.error("digits"));
+        }
+        for _ in 0..digits.len() {
+            cursor.bump();
+        }
+        Ok(Digits(digits))
+    }
+}
+
+const SCORES: Param<usize> = Param::new(
+    14,
+    "scores",
+    10,
+    "Scores of the recipes after the skipped ones making up part 1",
+)
+.at_most(1000);
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const PARAMS: &'static [&'static dyn Parameter] = &[&SCORES];
+    type Input = String;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::single_line(Self::DAY, input, |cursor| {
+            let Digits(digits) = Template::new("{}").parse(cursor)?;
+            Ok(digits)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let skip = input.parse::<usize>().ok()?;
+        let count = SCORES.get();
+        let mut recipes = String::from("37");
+        let mut elves = (0usize, 1usize);
+        while recipes.len() < skip + count {
+            let r_old = (
+                digit(recipes.as_bytes()[elves.0]),
+                digit(recipes.as_bytes()[elves.1]),
+            );
+            let r_new = format!("{}", r_old.0 + r_old.1);
+            recipes.push_str(&r_new);
+            elves = (
+                (elves.0 + r_old.0 as usize + 1) % recipes.len(),
+                (elves.1 + r_old.1 as usize + 1) % recipes.len(),
+            );
+        }
+        Some(recipes[skip..skip + count].to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let needle = input;
+        let mut recipes = String::from("37");
+        let mut elves = (0usize, 1usize);
+        let mut matcher = Matcher::new(needle.as_bytes());
+        matcher.consume_all(recipes.bytes());
+        while matcher.get_matches().is_empty() {
+            let r_old = (
+                digit(recipes.as_bytes()[elves.0]),
+                digit(recipes.as_bytes()[elves.1]),
+            );
+            let r_new = format!("{}", r_old.0 + r_old.1);
+            recipes.push_str(&r_new);
+            matcher.consume_all(r_new.bytes());
+            elves = (
+                (elves.0 + r_old.0 as usize + 1) % recipes.len(),
+                (elves.1 + r_old.1 as usize + 1) % recipes.len(),
+            );
+        }
+        matcher.get_matches().first().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matcher_finds_overlapping_matches() {
+        let mut matcher = Matcher::new(&[1, 2, 1]);
+        matcher.consume_all([1, 2, 1, 2, 1, 1].iter().copied());
+        assert_eq!(matcher.get_matches(), &[0, 2]);
+    }
+
+    #[test]
+    fn part1_examples() {
+        for &(recipes, scores) in &[
+            ("9", "5158916779"),
+            ("5", "0124515891"),
+            ("18", "9251071085"),
+            ("2018", "5941429882"),
+        ] {
+            let input = Day14::parse(recipes).unwrap();
+            assert_eq!(Day14::part1(&input).as_deref(), Some(scores));
+        }
+    }
+
+    #[test]
+    fn part2_examples() {
+        for &(scores, recipes) in &[("51589", 9), ("01245", 5), ("92510", 18), ("59414", 2018)] {
+            let input = Day14::parse(scores).unwrap();
+            assert_eq!(Day14::part2(&input), Some(recipes));
+        }
+    }
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "week1")]
+
+use std::collections::HashMap;
+
+use crate::error::{LineError, ParseError};
+use crate::json::Value;
+use crate::parse::{self, Cursor, Field, Template};
+use crate::{Answer, Solution};
+
+pub fn find_diff1(ids: &[String]) -> Option<(&str, &str, usize)> {
+    for (id_index, id1) in ids.iter().enumerate() {
+        for id2 in ids.iter().skip(id_index + 1) {
+            let mut diff_index = None;
+            for (char_index, (c1, c2)) in id1.chars().zip(id2.chars()).enumerate() {
+                if c1 != c2 {
+                    if diff_index.is_some() {
+                        diff_index = None;
+                        break;
+                    } else {
+                        diff_index = Some(char_index);
+                    }
+                }
+            }
+            if let Some(diff_index) = diff_index {
+                return Some((id1, id2, diff_index));
+            }
+        }
+    }
+    None
+}
+
+/// A box id, made of lowercase ASCII letters.
+struct BoxId(String);
+
+impl Field for BoxId {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        let id: String = cursor
+            .rest()
+            .chars()
+            .take_while(|c| c.is_ascii_lowercase())
+            .collect();
+        if id.is_empty() {
+            return Err(cursor.error("lowercase letter"));
+        }
+        for _ in 0..id.len() {
+            cursor.bump();
+        }
+        Ok(BoxId(id))
+    }
+}
+
+/// Number of ids with some letter exactly twice and exactly three times.
+pub struct Checksum {
+    pub twos: u64,
+    pub threes: u64,
+}
+
+impl std::fmt::Display for Checksum {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.twos * self.threes)
+    }
+}
+
+impl Answer for Checksum {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![("twos", self.twos.into()), ("threes", self.threes.into())]
+    }
+}
+
+/// The two ids that differ by a single letter, and the position of that letter.
+pub struct CommonLetters {
+    pub ids: (String, String),
+    pub index: usize,
+}
+
+impl std::fmt::Display for CommonLetters {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let id = &self.ids.0;
+        write!(f, "{}{}", &id[..self.index], &id[self.index + 1..])
+    }
+}
+
+impl Answer for CommonLetters {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("first_id", self.ids.0.as_str().into()),
+            ("second_id", self.ids.1.as_str().into()),
+            ("diff_index", self.index.into()),
+        ]
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<String>;
+    type Part1 = Checksum;
+    type Part2 = CommonLetters;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let id = Template::new("{}");
+        parse::lines(Self::DAY, input, |cursor| {
+            let BoxId(id) = id.parse(cursor)?;
+            Ok(id)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let mut sums = (0u64, 0u64);
+        let mut counts = HashMap::with_capacity(32);
+        for id in input.iter() {
+            counts.clear();
+            for c in id.chars() {
+                let appearances = counts.entry(c).or_insert(0);
+                *appearances += 1;
+            }
+            let mut increments = (0, 0);
+            for &value in counts.values() {
+                if increments.0 == 0 && value == 2 {
+                    increments.0 = 1;
+                    if increments.1 == 1 {
+                        break;
+                    }
+                } else if increments.1 == 0 && value == 3 {
+                    increments.1 = 1;
+                    if increments.0 == 1 {
+                        break;
+                    }
+                }
+            }
+            sums.0 += increments.0;
+            sums.1 += increments.1;
+        }
+        Some(Checksum {
+            twos: sums.0,
+            threes: sums.1,
+        })
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let (id1, id2, diff_index) = find_diff1(input)?;
+        Some(CommonLetters {
+            ids: (id1.to_string(), id2.to_string()),
+            index: diff_index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOX_IDS: &str = "abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab";
+    const SIMILAR_IDS: &str = "abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz";
+
+    #[test]
+    fn part1_example() {
+        let checksum = Day2::part1(&Day2::parse(BOX_IDS).unwrap()).unwrap();
+        assert_eq!((checksum.twos, checksum.threes), (4, 3));
+        assert_eq!(checksum.to_string(), "12");
+    }
+
+    #[test]
+    fn find_diff1_example() {
+        let ids = Day2::parse(SIMILAR_IDS).unwrap();
+        assert_eq!(find_diff1(&ids), Some(("fghij", "fguij", 2)));
+        assert_eq!(find_diff1(&ids[..1]), None);
+        assert_eq!(find_diff1(&Day2::parse("abcde\naxcye").unwrap()), None);
+    }
+
+    #[test]
+    fn parse_rejects_other_letters() {
+        let e = Day2::parse("abcde\né1").err().unwrap();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 1, "é1"));
+        let e = Day2::parse("abcde\nabCde").err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.expected.as_str()),
+            (2, 3, "end of line")
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let common = Day2::part2(&Day2::parse(SIMILAR_IDS).unwrap()).unwrap();
+        assert_eq!(common.to_string(), "fgij");
+    }
+}
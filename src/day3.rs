@@ -0,0 +1,149 @@
+#![cfg(feature = "week1")]
+
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
+
+use crate::error::{LineError, ParseError};
+use crate::grid::Grid;
+use crate::parse::{self, Cursor, Template};
+use crate::Solution;
+
+pub enum Cell {
+    Empty,
+    Claimed(usize),
+    Overlap(Vec<usize>),
+}
+
+#[cfg(feature = "visualize")]
+impl Cell {
+    /// `.` for unclaimed cells, `X` for overlaps, and the last digit of the claim's id.
+    fn draw(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Claimed(id) => char::from_digit((id % 10) as u32, 10).unwrap(),
+            Cell::Overlap(_) => 'X',
+        }
+    }
+}
+
+pub type Map = Grid<Cell>;
+
+fn empty_map(size: (usize, usize)) -> Map {
+    Grid::from_fn(size.0, size.1, |_| Cell::Empty)
+}
+
+#[cfg(feature = "visualize")]
+impl std::fmt::Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.display(Cell::draw))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Claim {
+    pub id: usize,
+    pub top_left: (usize, usize),
+    pub bottom_right: (usize, usize),
+}
+
+impl Claim {
+    pub fn new(id: usize, offset: (usize, usize), size: (usize, usize)) -> Claim {
+        Claim {
+            id,
+            top_left: offset,
+            bottom_right: (offset.0 + size.0 - 1, offset.1 + size.1 - 1),
+        }
+    }
+    pub fn parse(cursor: &mut Cursor) -> Result<Claim, LineError> {
+        let (id, left, top, width, height): (_, _, _, NonZeroUsize, NonZeroUsize) =
+            Template::new("#{} @ {},{}: {}x{}").parse(cursor)?;
+        Ok(Claim::new(id, (left, top), (width.get(), height.get())))
+    }
+}
+
+pub struct Claims {
+    pub map_size: (usize, usize),
+    pub claims: Vec<Claim>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Claims;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut map_size = (0, 0);
+        let claims = parse::lines(Self::DAY, input, Claim::parse)?;
+        claims.iter().for_each(|claim| {
+            if claim.bottom_right.0 >= map_size.0 {
+                map_size.0 = claim.bottom_right.0 + 1;
+            }
+            if claim.bottom_right.1 >= map_size.1 {
+                map_size.1 = claim.bottom_right.1 + 1;
+            }
+        });
+        Ok(Claims { map_size, claims })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let mut map = empty_map(input.map_size);
+        input.claims.iter().for_each(|claim| {
+            map.rect_mut(claim.top_left, claim.bottom_right)
+                .for_each(|cell| {
+                    match cell {
+                        Cell::Empty => *cell = Cell::Claimed(claim.id),
+                        Cell::Claimed(id) => *cell = Cell::Overlap(vec![*id, claim.id]),
+                        Cell::Overlap(ids) => ids.push(claim.id),
+                    };
+                });
+        });
+        Some(
+            map.cells()
+                .filter(|e| matches!(e, Cell::Overlap(_)))
+                .count(),
+        )
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let mut no_overlaps: HashSet<_> = input.claims.iter().map(|claim| claim.id).collect();
+        let mut map = empty_map(input.map_size);
+        input.claims.iter().for_each(|claim| {
+            map.rect_mut(claim.top_left, claim.bottom_right)
+                .for_each(|cell| {
+                    match cell {
+                        Cell::Empty => *cell = Cell::Claimed(claim.id),
+                        Cell::Claimed(id) => {
+                            no_overlaps.remove(&claim.id);
+                            no_overlaps.remove(id);
+                            *cell = Cell::Overlap(vec![*id, claim.id]);
+                        }
+                        Cell::Overlap(ids) => {
+                            no_overlaps.remove(&claim.id);
+                            ids.push(claim.id)
+                        }
+                    };
+                });
+        });
+        no_overlaps.into_iter().min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day3::part1(&Day3::parse(EXAMPLE).unwrap()), Some(4));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day3::part2(&Day3::parse(EXAMPLE).unwrap()), Some(3));
+    }
+}
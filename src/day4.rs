@@ -0,0 +1,437 @@
+#![cfg(feature = "week1")]
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::error::{LineError, ParseError};
+use crate::json::Value;
+use crate::parse::{self, Cursor, Field, Template};
+use crate::trace;
+use crate::{Answer, Solution};
+
+pub enum RecordType {
+    Shift(usize),
+    Sleep,
+    Wake,
+}
+
+impl std::fmt::Display for RecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordType::Shift(id) => write!(f, "Guard #{} begins shift", id),
+            RecordType::Sleep => write!(f, "falls asleep"),
+            RecordType::Wake => write!(f, "wakes up"),
+        }
+    }
+}
+
+/// Minute of the hour, between 00 and 59.
+struct Minute(usize);
+
+impl Field for Minute {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        let column = cursor.column();
+        let minute: usize = cursor.parse()?;
+        if minute < 60 {
+            Ok(Minute(minute))
+        } else {
+            Err(LineError::new(
+                column,
+                "minute between 00 and 59",
+                minute.to_string(),
+            ))
+        }
+    }
+}
+
+pub struct Record {
+    pub date: (usize, usize, usize),
+    pub time: (usize, usize),
+    pub info: RecordType,
+}
+
+impl Record {
+    pub fn parse(cursor: &mut Cursor) -> Result<Record, LineError> {
+        let (year, month, day, hour, Minute(minute)) =
+            Template::new("[{}-{}-{} {}:{}] ").parse_prefix(cursor)?;
+        let info = match cursor.peek() {
+            Some('G') => RecordType::Shift(Template::new("Guard #{} begins shift").parse(cursor)?),
+            Some('f') => {
+                Template::new("falls asleep").parse::<()>(cursor)?;
+                RecordType::Sleep
+            }
+            Some('w') => {
+                Template::new("wakes up").parse::<()>(cursor)?;
+                RecordType::Wake
+            }
+            _ => return Err(cursor.error("`Guard`, `falls asleep` or `wakes up`")),
+        };
+        Ok(Record {
+            date: (year, month, day),
+            time: (hour, minute),
+            info,
+        })
+    }
+}
+
+impl Ord for Record {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+        for (n1, n2) in [
+            self.date.0,
+            self.date.1,
+            self.date.2,
+            self.time.0,
+            self.time.1,
+        ]
+        .iter()
+        .zip(
+            [
+                other.date.0,
+                other.date.1,
+                other.date.2,
+                other.time.0,
+                other.time.1,
+            ]
+            .iter(),
+        ) {
+            let order = n1.cmp(n2);
+            if order != Ordering::Equal {
+                return order;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for Record {}
+
+impl PartialEq for Record {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl std::fmt::Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{:04}-{:02}-{:02} {:02}:{:02}] {}",
+            self.date.0, self.date.1, self.date.2, self.time.0, self.time.1, self.info
+        )
+    }
+}
+
+/// Sleep intervals (in minutes past midnight) for every guard.
+pub type Events = HashMap<usize, Vec<Range<usize>>>;
+
+/// The guard chosen by a strategy and the minute they are most often asleep.
+pub struct Choice {
+    pub guard: usize,
+    /// Total minutes the guard spent asleep.
+    pub asleep: usize,
+    pub minute: usize,
+    /// Number of days the guard was asleep during `minute`.
+    pub times: usize,
+}
+
+impl std::fmt::Display for Choice {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.guard * self.minute)
+    }
+}
+
+impl Answer for Choice {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("guard", self.guard.into()),
+            ("minutes_asleep", self.asleep.into()),
+            ("minute", self.minute.into()),
+            ("times_asleep_at_minute", self.times.into()),
+        ]
+    }
+
+    fn explain(&self) -> Vec<String> {
+        vec![
+            format!(
+                "guard #{} slept {} minutes in total",
+                self.guard, self.asleep
+            ),
+            format!(
+                "guard #{} was asleep most often at minute {}, on {} days",
+                self.guard, self.minute, self.times
+            ),
+            format!("{} * {} = {}", self.guard, self.minute, self),
+        ]
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Events;
+    type Part1 = Choice;
+    type Part2 = Choice;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<_> = input.lines().collect();
+        let mut records: Vec<_> = parse::lines(Self::DAY, input, Record::parse)?
+            .into_iter()
+            .enumerate()
+            .collect();
+        records.sort_unstable_by(|(_, r1), (_, r2)| r1.cmp(r2));
+        let mut events = HashMap::with_capacity(32);
+        let mut first = None;
+        let mut guard = None;
+        let mut sleep_minute = None;
+        for (index, record) in records.iter() {
+            let line = lines[*index];
+            // The template guarantees the `]` closing the timestamp and the
+            // `:` before its minute
+            let close = line.find(']').unwrap();
+            let info_column = close + 3;
+            let minute_column = line[..close].rfind(':').unwrap() + 2;
+            let found = match record.info {
+                RecordType::Shift(_) => "Guard",
+                RecordType::Sleep => "falls",
+                RecordType::Wake => "wakes",
+            };
+            let result = match (&record.info, guard, sleep_minute) {
+                (RecordType::Shift(id), _, Some(_)) => {
+                    guard = Some(*id);
+                    sleep_minute = None;
+                    Err(LineError::new(info_column, "`wakes up`", found))
+                }
+                (RecordType::Shift(id), _, None) => {
+                    guard = Some(*id);
+                    Ok(())
+                }
+                (_, None, _) => Err(LineError::new(info_column, "`Guard`", found)),
+                (RecordType::Sleep, Some(_), None) => {
+                    sleep_minute = Some(record.time.1);
+                    Ok(())
+                }
+                (RecordType::Sleep, Some(_), Some(_)) => {
+                    Err(LineError::new(info_column, "`wakes up`", found))
+                }
+                (RecordType::Wake, Some(_), None) => {
+                    Err(LineError::new(info_column, "`falls asleep`", found))
+                }
+                (RecordType::Wake, Some(_), Some(from)) if record.time.1 <= from => {
+                    sleep_minute = None;
+                    Err(LineError::new(
+                        minute_column,
+                        format!("minute after {:02}", from),
+                        format!("{:02}", record.time.1),
+                    ))
+                }
+                (RecordType::Wake, Some(guard), Some(from)) => {
+                    let (year, month, day) = record.date;
+                    trace!(
+                        "sleep",
+                        guard = guard,
+                        date = format!("{:04}-{:02}-{:02}", year, month, day),
+                        from = from,
+                        to = record.time.1,
+                    );
+                    events
+                        .entry(guard)
+                        .or_insert_with(|| Vec::with_capacity(32))
+                        .push(from..record.time.1);
+                    sleep_minute = None;
+                    Ok(())
+                }
+            };
+            if let Err(e) = result {
+                let e = parse::report(e.at(Self::DAY, *index, line));
+                if !parse::collecting() {
+                    return Err(e);
+                }
+                first.get_or_insert(e);
+            }
+        }
+        if sleep_minute.is_some() {
+            let e = parse::report(parse::end_of_input(Self::DAY, input, "`wakes up`"));
+            first.get_or_insert(e);
+        }
+        first.map_or(Ok(events), Err)
+    }
+
+    fn part1(events: &Self::Input) -> Option<Self::Part1> {
+        let total_minutes: HashMap<usize, usize> = events
+            .iter()
+            .map(|(guard, days)| {
+                (
+                    *guard,
+                    days.iter().map(|asleep| asleep.end - asleep.start).sum(),
+                )
+            })
+            .collect();
+        let guard = total_minutes
+            .iter()
+            .fold(
+                (&0, &0),
+                |max, current| if current.1 > max.1 { current } else { max },
+            )
+            .0;
+        let days = events.get(guard)?;
+        let minutes: Vec<_> = (0..60)
+            .map(|hour| days.iter().filter(|asleep| asleep.contains(&hour)).count())
+            .collect();
+        let (max_minute, times) =
+            minutes.iter().enumerate().fold(
+                (0, &0),
+                |max, current| if current.1 > max.1 { current } else { max },
+            );
+        Some(Choice {
+            guard: *guard,
+            asleep: total_minutes[guard],
+            minute: max_minute,
+            times: *times,
+        })
+    }
+
+    fn part2(events: &Self::Input) -> Option<Self::Part2> {
+        let guard_minutes: HashMap<usize, Vec<usize>> = events
+            .iter()
+            .map(|(guard, days)| {
+                (
+                    *guard,
+                    (0..60)
+                        .map(|hour| days.iter().filter(|asleep| asleep.contains(&hour)).count())
+                        .collect(),
+                )
+            })
+            .collect();
+        let (guard, (max_minute, times)) = guard_minutes
+            .iter()
+            .map(|(guard, minutes)| {
+                (
+                    *guard,
+                    minutes
+                        .iter()
+                        .enumerate()
+                        .max_by(|(_, v1), (_, v2)| v1.cmp(v2))
+                        .unwrap(),
+                )
+            })
+            .max_by(|(_, (_, v1)), (_, (_, v2))| v1.cmp(v2))?;
+        Some(Choice {
+            guard,
+            asleep: events[&guard].iter().map(|asleep| asleep.len()).sum(),
+            minute: max_minute,
+            times: *times,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+
+    #[test]
+    fn parse_example_out_of_order() {
+        let mut lines: Vec<_> = EXAMPLE.lines().collect();
+        lines.reverse();
+        let events = Day4::parse(&lines.join("\n")).unwrap();
+        assert_eq!(events, Day4::parse(EXAMPLE).unwrap());
+        assert_eq!(events[&10], vec![5..25, 30..55, 24..29]);
+    }
+
+    #[test]
+    fn parse_orphan_records() {
+        let error = |input: &str| {
+            let e = Day4::parse(input).err().unwrap();
+            (e.line, e.column, e.expected, e.found)
+        };
+        assert_eq!(
+            error("[1518-11-01 00:00] Guard #10 begins shift\n[1518-10-31 23:58] falls asleep"),
+            (2, 20, String::from("`Guard`"), String::from("falls"))
+        );
+        assert_eq!(
+            error("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:25] wakes up"),
+            (2, 20, String::from("`falls asleep`"), String::from("wakes"))
+        );
+        assert_eq!(
+            error("[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls asleep"),
+            (
+                2,
+                32,
+                String::from("`wakes up`"),
+                String::from("end of input")
+            )
+        );
+        // A record at the same minute sorts after the sleep, but cannot end it
+        assert_eq!(
+            error(
+                "[1518-11-01 00:00] Guard #10 begins shift\n\
+                 [1518-11-01 00:05] falls asleep\n\
+                 [1518-11-01 00:05] wakes up"
+            ),
+            (3, 16, String::from("minute after 05"), String::from("05"))
+        );
+    }
+
+    #[test]
+    fn all_errors_in_order_of_time() {
+        let input = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-10-31 23:58] wakes up
+[1518-11-01 00:10] falls asleep
+[1518-11-01 00:20] falls asleep
+[1518-11-01 00:30] wakes up
+[1518-11-01 00:40] wakes up";
+        let errors = parse::all_errors(|| Day4::parse(input));
+        let lines: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(lines, vec![(2, 20), (4, 20), (6, 20)]);
+        assert_eq!(Day4::parse(input).err(), Some(errors[0].clone()));
+    }
+
+    #[test]
+    fn part1_example() {
+        let choice = Day4::part1(&Day4::parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!((choice.guard, choice.asleep, choice.minute), (10, 50, 24));
+        assert_eq!(choice.to_string(), "240");
+        assert_eq!(
+            choice.explain(),
+            vec![
+                "guard #10 slept 50 minutes in total",
+                "guard #10 was asleep most often at minute 24, on 2 days",
+                "10 * 24 = 240",
+            ]
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let choice = Day4::part2(&Day4::parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!((choice.guard, choice.minute, choice.times), (99, 45, 3));
+        assert_eq!(choice.to_string(), "4455");
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "week1")]
+
+use crate::error::{LineError, ParseError};
+use crate::json::Value;
+use crate::parse::{self, Cursor, Field, Template};
+use crate::{Answer, Solution};
+
+/// Length of the polymer left once every pair of adjacent units of the same
+/// type and opposite polarity has reacted, in a single pass: each unit either
+/// reacts with the last unit left so far or is left too.
+fn react(polymer: impl Iterator<Item = char>) -> usize {
+    let mut left: Vec<char> = Vec::new();
+    for unit in polymer {
+        match left.last() {
+            Some(&last) if last != unit && last.eq_ignore_ascii_case(&unit) => {
+                left.pop();
+            }
+            _ => left.push(unit),
+        }
+    }
+    left.len()
+}
+
+/// A polymer, made of units that are ASCII letters.
+struct Units(String);
+
+impl Field for Units {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        let units: String = cursor
+            .rest()
+            .chars()
+            .take_while(|c| c.is_ascii_alphabetic())
+            .collect();
+        if units.is_empty() {
+            return Err(cursor.error("polymer units"));
+        }
+        for _ in 0..units.len() {
+            cursor.bump();
+        }
+        Ok(Units(units))
+    }
+}
+
+/// Length of the shortest polymer, obtained by removing every `unit`.
+pub struct Improved {
+    pub unit: char,
+    pub length: usize,
+}
+
+impl std::fmt::Display for Improved {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.length)
+    }
+}
+
+impl Answer for Improved {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![("removed_unit", self.unit.into())]
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = Improved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::single_line(Self::DAY, input, |cursor| {
+            let Units(units) = Template::new("{}").parse(cursor)?;
+            Ok(units)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(react(input.chars()))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        "abcdefghijklmnopqrstuvwxyz"
+            .chars()
+            .map(|remove| Improved {
+                unit: remove,
+                length: react(input.chars().filter(|c| c.to_ascii_lowercase() != remove)),
+            })
+            .min_by_key(|improved| improved.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "dabAcCaCBAcCcaDA";
+
+    #[test]
+    fn react_examples() {
+        assert_eq!(react("aA".chars()), 0);
+        assert_eq!(react("abBA".chars()), 0);
+        assert_eq!(react("abAB".chars()), 4);
+        assert_eq!(react("aabAAB".chars()), 6);
+    }
+
+    #[test]
+    fn parse_stops_at_units() {
+        assert_eq!(Day5::parse("dabA\n").unwrap(), "dabA");
+        assert_eq!(Day5::parse("dab-A").unwrap_err().column, 4);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day5::part1(&Day5::parse(EXAMPLE).unwrap()), Some(10));
+    }
+
+    #[test]
+    fn part2_example() {
+        let improved = Day5::part2(&Day5::parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!((improved.unit, improved.length), ('c', 4));
+    }
+}
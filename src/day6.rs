@@ -0,0 +1,195 @@
+#![cfg(feature = "week1")]
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::json::Value;
+use crate::params::{Param, Parameter};
+use crate::parse::{self, Template};
+use crate::point::{Bounds, Point};
+use crate::{Answer, Solution};
+
+pub type Position = Point<usize>;
+
+fn find_closest_danger<'a, I>(dangers: I, position: Position) -> Option<usize>
+where
+    I: Iterator<Item = &'a Position>,
+{
+    let mut dangers = dangers;
+    let first = *dangers.next()?;
+    Some(
+        dangers
+            .enumerate()
+            .fold(
+                (Some((0, first)), position.manhattan(first)),
+                |closest, (index, &danger)| {
+                    let index = index + 1;
+                    let distance = position.manhattan(danger);
+                    if distance < closest.1 {
+                        (Some((index, danger)), distance)
+                    } else if distance == closest.1 {
+                        (None, distance)
+                    } else {
+                        closest
+                    }
+                },
+            )
+            .0?
+            .0,
+    )
+}
+
+/// Grid over the bounding box of the dangers, holding `f` of each position.
+fn around_dangers<T>(dangers: &[Position], mut f: impl FnMut(Position) -> T) -> Option<Grid<T>> {
+    let bounds = Bounds::around(dangers.iter().copied())?;
+    let size = bounds.extent() + Point::new(1, 1);
+    Some(Grid::from_fn(size.x, size.y, |position| {
+        f(position + bounds.min)
+    }))
+}
+
+const MAX_TOTAL_DISTANCE: Param<usize> = Param::new(
+    6,
+    "max_total_distance",
+    10000,
+    "Total distance to every danger that safe positions are below",
+);
+
+/// Number of positions whose total distance to every danger is below `limit`.
+fn safe_area(dangers: &[Position], limit: usize) -> Option<usize> {
+    let total_distances = around_dangers(dangers, |position| {
+        dangers
+            .iter()
+            .map(|&danger| position.manhattan(danger))
+            .sum::<usize>()
+    })?;
+    Some(
+        total_distances
+            .cells()
+            .filter(|&&distance| distance < limit)
+            .count(),
+    )
+}
+
+/// The largest finite area closest to a single danger.
+pub struct Region {
+    pub size: usize,
+    pub danger: Position,
+    /// Dangers whose areas reach the edge of the bounding box, in input order.
+    pub infinite: Vec<Position>,
+}
+
+impl std::fmt::Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.size)
+    }
+}
+
+impl Answer for Region {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("x", self.danger.x.into()),
+            ("y", self.danger.y.into()),
+            ("infinite_areas", self.infinite.len().into()),
+        ]
+    }
+
+    fn explain(&self) -> Vec<String> {
+        let infinite: Vec<_> = self
+            .infinite
+            .iter()
+            .map(|danger| format!("{}, {}", danger.x, danger.y))
+            .collect();
+        vec![
+            format!(
+                "excluded as infinite, reaching the edge: {}",
+                infinite.join("; ")
+            ),
+            format!(
+                "danger {}, {} is closest to {} positions, the most of the rest",
+                self.danger.x, self.danger.y, self.size
+            ),
+        ]
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const PARAMS: &'static [&'static dyn Parameter] = &[&MAX_TOTAL_DISTANCE];
+    type Input = Vec<Position>;
+    type Part1 = Region;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let coordinate = Template::new("{}, {}");
+        parse::lines(Self::DAY, input, |cursor| {
+            coordinate.parse(cursor).map(|(x, y)| Point::new(x, y))
+        })
+    }
+
+    fn part1(dangers: &Self::Input) -> Option<Self::Part1> {
+        let closest = around_dangers(dangers, |position| {
+            find_closest_danger(dangers.iter(), position)
+        })?;
+
+        // Areas reaching the edge of the box go on forever
+        let edge = closest
+            .row(0)
+            .chain(closest.row(closest.height() - 1))
+            .chain(closest.column(0))
+            .chain(closest.column(closest.width() - 1));
+        let infinite: HashSet<_> = edge.flatten().collect();
+
+        let mut areas = HashMap::with_capacity(dangers.len());
+        for index in closest.cells().flatten() {
+            if !infinite.contains(index) {
+                *areas.entry(index).or_insert(0) += 1;
+            }
+        }
+
+        // Ties go to the danger listed first
+        let (&&index, &size) = areas
+            .iter()
+            .max_by_key(|&(&index, &size)| (size, Reverse(index)))?;
+        let mut infinite: Vec<_> = infinite.into_iter().copied().collect();
+        infinite.sort_unstable();
+        Some(Region {
+            size,
+            danger: dangers[index],
+            infinite: infinite.into_iter().map(|index| dangers[index]).collect(),
+        })
+    }
+
+    fn part2(dangers: &Self::Input) -> Option<Self::Part2> {
+        safe_area(dangers, MAX_TOTAL_DISTANCE.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9";
+
+    #[test]
+    fn part1_example() {
+        let region = Day6::part1(&Day6::parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!((region.size, region.danger), (17, Point::new(5, 5)));
+        assert_eq!(
+            region.explain(),
+            vec![
+                "excluded as infinite, reaching the edge: 1, 1; 1, 6; 8, 3; 8, 9",
+                "danger 5, 5 is closest to 17 positions, the most of the rest",
+            ]
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(safe_area(&Day6::parse(EXAMPLE).unwrap(), 32), Some(16));
+    }
+}
@@ -0,0 +1,353 @@
+#![cfg(feature = "week1")]
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::error::{LineError, ParseError};
+use crate::params::{Param, Parameter};
+use crate::parse::{self, Cursor, Field, Template};
+use crate::step::Steppable;
+use crate::trace;
+use crate::{Answer, Solution};
+
+fn node2time(node: char, base_time: usize) -> usize {
+    base_time + 1 + (node as usize - 'A' as usize)
+}
+
+/// A step taken by a worker, with the seconds left to complete it.
+#[derive(Clone, Copy, Debug)]
+struct Work {
+    step: char,
+    remaining: usize,
+}
+
+pub const WORKERS: Param<usize> = Param::new(7, "workers", 5, "Workers assembling the steps")
+    .at_least(1)
+    .at_most(26);
+pub const BASE_TIME: Param<usize> = Param::new(
+    7,
+    "base_time",
+    60,
+    "Seconds added to every step, on top of 1 for A, 2 for B and so on",
+);
+
+/// A step done by a worker, from second `start` to `end`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Task {
+    pub step: char,
+    pub worker: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Workers assembling the steps, one second at a time.
+#[derive(Clone)]
+pub struct Assembly {
+    /// Steps not taken by a worker yet, with the steps they still wait for.
+    nodes: BTreeMap<char, BTreeSet<char>>,
+    workers: Vec<Option<Work>>,
+    base_time: usize,
+    /// The current second, whose work is already assigned.
+    pub second: usize,
+    /// Steps completed so far, in order of completion.
+    pub done: Vec<Task>,
+}
+
+impl Assembly {
+    /// Assembly with `workers` workers at second 0, when step `A` takes
+    /// `base_time + 1` seconds, `B` takes `base_time + 2` and so on.
+    pub fn new(dependencies: &Dependencies, workers: usize, base_time: usize) -> Self {
+        let mut nodes: BTreeMap<char, BTreeSet<char>> = BTreeMap::new();
+        dependencies.iter().for_each(|&(requirement, step)| {
+            nodes.entry(requirement).or_default();
+            nodes.entry(step).or_default().insert(requirement);
+        });
+        let mut assembly = Assembly {
+            nodes,
+            workers: vec![None; workers],
+            base_time,
+            second: 0,
+            done: Vec::new(),
+        };
+        assembly.assign();
+        assembly
+    }
+
+    /// Idle workers take the first available steps.
+    fn assign(&mut self) {
+        for (index, worker) in self.workers.iter_mut().enumerate() {
+            if worker.is_some() {
+                continue;
+            }
+            let step = match self
+                .nodes
+                .iter()
+                .find(|(_, requirements)| requirements.is_empty())
+            {
+                Some((&step, _)) => step,
+                None => break,
+            };
+            self.nodes.remove(&step);
+            *worker = Some(Work {
+                step,
+                remaining: node2time(step, self.base_time),
+            });
+            trace!("assign", second = self.second, worker = index, step = step);
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.workers.iter().all(Option::is_none)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.nodes.is_empty() && self.is_idle()
+    }
+
+    /// Whether the remaining steps wait on each other in a cycle, leaving
+    /// every worker idle for good.
+    pub fn is_stuck(&self) -> bool {
+        !self.nodes.is_empty() && self.is_idle()
+    }
+
+    /// Moves to the next second: busy workers go on with or finish their step,
+    /// then idle workers take the first available steps.
+    pub fn tick(&mut self) {
+        self.second += 1;
+        for (index, worker) in self.workers.iter_mut().enumerate() {
+            let work = match worker {
+                Some(work) if work.remaining > 1 => {
+                    work.remaining -= 1;
+                    continue;
+                }
+                Some(work) => *work,
+                None => continue,
+            };
+            *worker = None;
+            self.nodes.values_mut().for_each(|requirements| {
+                requirements.remove(&work.step);
+            });
+            self.done.push(Task {
+                step: work.step,
+                worker: index,
+                start: self.second - node2time(work.step, self.base_time),
+                end: self.second,
+            });
+            trace!(
+                "complete",
+                second = self.second,
+                worker = index,
+                step = work.step
+            );
+        }
+        self.assign();
+    }
+}
+
+/// Each worker's step and its remaining seconds, then the current second.
+#[cfg(feature = "visualize")]
+impl std::fmt::Display for Assembly {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for worker in self.workers.iter() {
+            write!(f, "|")?;
+            match worker {
+                Some(work) => write!(f, "{}{:02}", work.step, work.remaining)?,
+                None => write!(f, "---")?,
+            }
+        }
+        write!(f, "| {:04}", self.second)
+    }
+}
+
+impl Steppable for Assembly {
+    fn step(&mut self) -> bool {
+        if self.is_done() || self.is_stuck() {
+            return false;
+        }
+        self.tick();
+        true
+    }
+
+    fn values(&self) -> Vec<(&'static str, i64)> {
+        let busy = self
+            .workers
+            .iter()
+            .filter(|worker| worker.is_some())
+            .count();
+        vec![
+            ("second", self.second as i64),
+            ("remaining", (self.nodes.len() + busy) as i64),
+            ("busy", busy as i64),
+        ]
+    }
+
+    #[cfg(feature = "visualize")]
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Every step done by the workers, and the time needed to complete them.
+pub struct Schedule {
+    pub time: usize,
+    pub workers: usize,
+    pub tasks: Vec<Task>,
+}
+
+impl std::fmt::Display for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.time)
+    }
+}
+
+impl Answer for Schedule {
+    fn explain(&self) -> Vec<String> {
+        let mut lines: Vec<_> = (0..self.workers)
+            .map(|worker| {
+                let tasks: Vec<_> = self
+                    .tasks
+                    .iter()
+                    .filter(|task| task.worker == worker)
+                    .map(|task| format!("{} {}-{}", task.step, task.start, task.end))
+                    .collect();
+                if tasks.is_empty() {
+                    format!("worker {}: idle", worker + 1)
+                } else {
+                    format!("worker {}: {}", worker + 1, tasks.join(", "))
+                }
+            })
+            .collect();
+        lines.push(format!("every step is done at second {}", self.time));
+        lines
+    }
+}
+
+/// The steps done by `workers` until every step is complete, when step `A`
+/// takes `base_time + 1` seconds, `B` takes `base_time + 2` and so on, or
+/// `None` if the dependencies are cyclic.
+fn schedule(dependencies: &Dependencies, workers: usize, base_time: usize) -> Option<Schedule> {
+    let mut assembly = Assembly::new(dependencies, workers, base_time);
+    while !assembly.is_done() {
+        if assembly.is_stuck() {
+            return None;
+        }
+        assembly.tick();
+    }
+    Some(Schedule {
+        time: assembly.second,
+        workers,
+        tasks: assembly.done,
+    })
+}
+
+/// A step name, which is a single uppercase letter.
+struct Step(char);
+
+impl Field for Step {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        cursor
+            .char("step letter", |c| c.is_ascii_uppercase())
+            .map(Step)
+    }
+}
+
+/// Pairs of `(requirement, step)`.
+pub type Dependencies = Vec<(char, char)>;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const PARAMS: &'static [&'static dyn Parameter] = &[&WORKERS, &BASE_TIME];
+    type Input = Dependencies;
+    type Part1 = String;
+    type Part2 = Schedule;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let dependency = Template::new("Step {} must be finished before step {} can begin.");
+        parse::lines(Self::DAY, input, |cursor| {
+            let (Step(requirement), Step(step)) = dependency.parse(cursor)?;
+            Ok((requirement, step))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let mut nodes = BTreeMap::new();
+        let mut result = String::new();
+        input.iter().for_each(|&(requirement, step)| {
+            nodes.entry(requirement).or_insert_with(BTreeSet::new);
+            nodes
+                .entry(step)
+                .or_insert_with(BTreeSet::new)
+                .insert(requirement);
+        });
+        while let Some(current) = nodes.iter().find(|node| node.1.is_empty()) {
+            let current = *current.0;
+            result.push(current);
+            nodes.remove(&current);
+            nodes.iter_mut().for_each(|node| {
+                node.1.remove(&current);
+            });
+        }
+        nodes.is_empty().then_some(result)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        schedule(input, WORKERS.get(), BASE_TIME.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.";
+
+    #[test]
+    fn part1_example() {
+        let order = Day7::part1(&Day7::parse(EXAMPLE).unwrap());
+        assert_eq!(order.as_deref(), Some("CABDFE"));
+    }
+
+    #[test]
+    #[cfg(feature = "visualize")]
+    fn assembly_example() {
+        let mut assembly = Assembly::new(&Day7::parse(EXAMPLE).unwrap(), 2, 0);
+        assert_eq!(assembly.to_string(), "|C03|---| 0000");
+        (0..3).for_each(|_| assembly.tick());
+        assert_eq!(assembly.to_string(), "|A01|F06| 0003");
+    }
+
+    #[test]
+    fn part2_example() {
+        let schedule = schedule(&Day7::parse(EXAMPLE).unwrap(), 2, 0).unwrap();
+        assert_eq!(schedule.time, 15);
+        assert_eq!(
+            schedule.explain(),
+            vec![
+                "worker 1: C 0-3, A 3-4, B 4-6, D 6-10, E 10-15",
+                "worker 2: F 3-9",
+                "every step is done at second 15",
+            ]
+        );
+    }
+
+    #[test]
+    fn cyclic_dependencies() {
+        let input = Day7::parse(
+            "\
+Step C must be finished before step A can begin.
+Step A must be finished before step B can begin.
+Step B must be finished before step A can begin.",
+        )
+        .unwrap();
+        assert_eq!(Day7::part1(&input), None);
+        assert!(schedule(&input, 2, 0).is_none());
+    }
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "week2")]
+
+use crate::error::{LineError, ParseError};
+use crate::parse;
+use crate::Solution;
+
+pub fn sum_of_entries<T>(tree: &mut T, node_count: usize) -> usize
+where
+    T: Iterator<Item = usize>,
+{
+    let mut sum = 0;
+    for _ in 0..node_count {
+        let child_count = tree.next().unwrap();
+        let entry_count = tree.next().unwrap();
+        sum += sum_of_entries(tree, child_count);
+        sum += (0..entry_count)
+            .map(|_| tree.next().unwrap())
+            .sum::<usize>();
+    }
+    sum
+}
+
+pub fn value_of_entry<T>(tree: &mut T) -> usize
+where
+    T: Iterator<Item = usize>,
+{
+    let child_count = tree.next().unwrap();
+    let entry_count = tree.next().unwrap();
+    if child_count > 0 {
+        let child_values: Vec<_> = (0..child_count).map(|_| value_of_entry(tree)).collect();
+        (0..entry_count)
+            .map(|_| tree.next().unwrap())
+            .map(|index| {
+                if index > 0 {
+                    *child_values.get(index - 1).unwrap_or(&0)
+                } else {
+                    0
+                }
+            })
+            .sum::<usize>()
+    } else {
+        (0..entry_count)
+            .map(|_| tree.next().unwrap())
+            .sum::<usize>()
+    }
+}
+
+/// Number of entries taken by the node at the start of `tree`, or `None` if it is truncated.
+fn node_size(tree: &[usize]) -> Option<usize> {
+    let child_count = *tree.first()?;
+    let entry_count = *tree.get(1)?;
+    let mut size = 2;
+    for _ in 0..child_count {
+        size += node_size(tree.get(size..)?)?;
+    }
+    size += entry_count;
+    if size <= tree.len() {
+        Some(size)
+    } else {
+        None
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Every number along with its line index and column, to locate trailing entries
+        let numbers: Vec<(usize, usize, usize)> = parse::lines(Self::DAY, input, |cursor| {
+            let mut numbers = Vec::new();
+            cursor.skip_whitespace();
+            while !cursor.rest().is_empty() {
+                numbers.push((cursor.column(), cursor.parse()?));
+                cursor.skip_whitespace();
+            }
+            Ok(numbers)
+        })?
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, numbers)| {
+            numbers
+                .into_iter()
+                .map(move |(column, number)| (index, column, number))
+        })
+        .collect();
+        let tree: Vec<_> = numbers.iter().map(|&(_, _, number)| number).collect();
+        let size = node_size(&tree)
+            .ok_or_else(|| parse::end_of_input(Self::DAY, input, "child node or metadata entry"))?;
+        if let Some(&(index, column, number)) = numbers.get(size) {
+            let line = input.lines().nth(index).unwrap();
+            return Err(
+                LineError::new(column, "end of input", number.to_string()).at(
+                    Self::DAY,
+                    index,
+                    line,
+                ),
+            );
+        }
+        Ok(tree)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(sum_of_entries(&mut input.iter().copied(), 1))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        Some(value_of_entry(&mut input.iter().copied()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+
+    fn tree() -> Vec<usize> {
+        EXAMPLE.split(' ').map(|n| n.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn sum_of_entries_example() {
+        assert_eq!(sum_of_entries(&mut tree().into_iter(), 1), 138);
+    }
+
+    #[test]
+    fn value_of_entry_example() {
+        assert_eq!(value_of_entry(&mut tree().into_iter()), 66);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day8::part1(&Day8::parse(EXAMPLE).unwrap()), Some(138));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day8::part2(&Day8::parse(EXAMPLE).unwrap()), Some(66));
+    }
+}
@@ -0,0 +1,345 @@
+#![cfg(all(feature = "week2", feature = "heavy"))]
+
+use std::num::NonZeroUsize;
+
+use crate::error::ParseError;
+use crate::json::Value;
+use crate::params::{Param, Parameter};
+use crate::parse::{self, Template};
+use crate::step::Steppable;
+use crate::trace;
+use crate::{Answer, Solution};
+
+/// Marble circle stored in a `Vec`, inserting and removing in place.
+#[derive(Clone)]
+pub struct VecGame {
+    pub player: usize,
+    pub scores: Vec<usize>,
+    pub position: usize,
+    pub next_value: usize,
+    pub marbles: Vec<usize>,
+}
+
+impl VecGame {
+    pub fn new(players: usize) -> VecGame {
+        VecGame {
+            player: 0,
+            scores: vec![0; players],
+            position: 0,
+            next_value: 1,
+            marbles: vec![0],
+        }
+    }
+    pub fn next(&mut self) {
+        if !self.next_value.is_multiple_of(23) {
+            let insert_after = (self.position + 1) % self.marbles.len();
+            self.marbles.insert(insert_after + 1, self.next_value);
+            self.position = insert_after + 1;
+        } else {
+            let take_at = (self.position + self.marbles.len() - 7) % self.marbles.len();
+            let removed = self.marbles.remove(take_at);
+            self.scores[self.player] += self.next_value + removed;
+            self.position = take_at;
+            trace!(
+                "score",
+                player = self.player + 1,
+                marble = self.next_value,
+                removed = removed,
+                score = self.scores[self.player],
+            );
+        }
+        self.player = (self.player + 1) % self.scores.len();
+        self.next_value += 1;
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Node {
+    pub next: usize,
+    pub previous: usize,
+    pub value: usize,
+}
+
+/// Marble circle stored as a doubly linked list in an arena.
+pub struct ListGame {
+    pub player: usize,
+    pub scores: Vec<usize>,
+    pub position: usize,
+    pub next_value: usize,
+    pub marbles: Vec<Node>,
+}
+
+impl ListGame {
+    pub fn new(players: usize) -> ListGame {
+        ListGame {
+            player: 0,
+            scores: vec![0; players],
+            position: 0,
+            next_value: 1,
+            marbles: vec![Node {
+                next: 0,
+                previous: 0,
+                value: 0,
+            }],
+        }
+    }
+    fn insert(&mut self, value: usize) {
+        let node = self.marbles[self.position];
+        self.marbles.push(Node {
+            next: node.next,
+            previous: self.position,
+            value,
+        });
+        let new_position = self.marbles.len() - 1;
+        self.marbles[node.next].previous = new_position;
+        self.marbles[self.position].next = new_position;
+        self.position = new_position;
+    }
+    fn remove(&mut self) -> usize {
+        let node = self.marbles[self.position];
+        let previous = node.previous;
+        let next = node.next;
+        self.marbles[previous].next = next;
+        self.marbles[next].previous = previous;
+        self.position = next;
+        node.value
+    }
+    fn move_forward(&mut self, count: usize) {
+        for _ in 0..count {
+            self.position = self.marbles[self.position].next;
+        }
+    }
+    fn move_back(&mut self, count: usize) {
+        for _ in 0..count {
+            self.position = self.marbles[self.position].previous;
+        }
+    }
+    pub fn next(&mut self) {
+        if !self.next_value.is_multiple_of(23) {
+            self.move_forward(1);
+            self.insert(self.next_value);
+        } else {
+            self.move_back(7);
+            let removed = self.remove();
+            self.scores[self.player] += self.next_value + removed;
+            trace!(
+                "score",
+                player = self.player + 1,
+                marble = self.next_value,
+                removed = removed,
+                score = self.scores[self.player],
+            );
+        }
+        self.player = (self.player + 1) % self.scores.len();
+        self.next_value += 1;
+    }
+}
+
+/// Number of marbles shown around the current one.
+#[cfg(feature = "visualize")]
+const SHOWN_MARBLES: usize = 10;
+
+/// A game played marble by marble, up to the last marble of the rules.
+#[derive(Clone)]
+pub struct Circle {
+    pub game: VecGame,
+    pub last_marble: usize,
+}
+
+impl Circle {
+    pub fn new(rules: &Rules) -> Self {
+        Circle {
+            game: VecGame::new(rules.players),
+            last_marble: rules.turns,
+        }
+    }
+}
+
+/// The current player and the circle as in the puzzle, the current marble in
+/// parentheses, eliding marbles far from the current one.
+#[cfg(feature = "visualize")]
+impl std::fmt::Display for Circle {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let game = &self.game;
+        // The player who placed the last marble
+        let player = (game.player + game.scores.len() - 1) % game.scores.len();
+        if game.next_value == 1 {
+            write!(f, "[-]")?;
+        } else {
+            write!(f, "[{}]", player + 1)?;
+        }
+        let shown = game.position.saturating_sub(SHOWN_MARBLES)
+            ..(game.position + SHOWN_MARBLES + 1).min(game.marbles.len());
+        if shown.start > 0 {
+            write!(f, " ...")?;
+        }
+        for index in shown.clone() {
+            if index == game.position {
+                write!(f, " ({})", game.marbles[index])?;
+            } else {
+                write!(f, " {}", game.marbles[index])?;
+            }
+        }
+        if shown.end < game.marbles.len() {
+            write!(f, " ...")?;
+        }
+        Ok(())
+    }
+}
+
+impl Steppable for Circle {
+    fn step(&mut self) -> bool {
+        if self.game.next_value > self.last_marble {
+            return false;
+        }
+        self.game.next();
+        true
+    }
+
+    fn values(&self) -> Vec<(&'static str, i64)> {
+        let game = &self.game;
+        vec![
+            ("marble", game.next_value as i64 - 1),
+            ("marbles", game.marbles.len() as i64),
+            (
+                "high_score",
+                game.scores.iter().copied().max().unwrap_or(0) as i64,
+            ),
+        ]
+    }
+
+    #[cfg(feature = "visualize")]
+    fn show(&self) -> String {
+        self.to_string()
+    }
+}
+
+pub struct Rules {
+    pub players: usize,
+    pub turns: usize,
+}
+
+/// The player with the highest score (numbered from 1).
+pub struct Winner {
+    pub player: usize,
+    pub score: usize,
+}
+
+impl Winner {
+    fn of(scores: Vec<usize>) -> Option<Winner> {
+        scores
+            .into_iter()
+            .enumerate()
+            .max_by_key(|&(_, score)| score)
+            .map(|(player, score)| Winner {
+                player: player + 1,
+                score,
+            })
+    }
+}
+
+impl std::fmt::Display for Winner {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.score)
+    }
+}
+
+impl Answer for Winner {
+    fn values(&self) -> Vec<(&'static str, Value)> {
+        vec![("winning_player", self.player.into())]
+    }
+}
+
+const MARBLE_FACTOR: Param<usize> = Param::new(
+    9,
+    "marble_factor",
+    100,
+    "How many times more marbles part 2 plays than part 1",
+)
+.at_most(1000);
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const PARAMS: &'static [&'static dyn Parameter] = &[&MARBLE_FACTOR];
+    type Input = Rules;
+    type Part1 = Winner;
+    type Part2 = Winner;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::single_line(Self::DAY, input, |cursor| {
+            let (players, turns): (NonZeroUsize, _) =
+                Template::new("{} players; last marble is worth {} points").parse(cursor)?;
+            Ok(Rules {
+                players: players.get(),
+                turns,
+            })
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let mut game = VecGame::new(input.players);
+        (0..input.turns).for_each(|_| game.next());
+        Winner::of(game.scores)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let mut game = ListGame::new(input.players);
+        let turns = input.turns.checked_mul(MARBLE_FACTOR.get())?;
+        (0..turns).for_each(|_| game.next());
+        Winner::of(game.scores)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &[(&str, usize)] = &[
+        ("9 players; last marble is worth 25 points", 32),
+        ("10 players; last marble is worth 1618 points", 8317),
+        ("13 players; last marble is worth 7999 points", 146373),
+        ("17 players; last marble is worth 1104 points", 2764),
+        ("21 players; last marble is worth 6111 points", 54718),
+        ("30 players; last marble is worth 5807 points", 37305),
+    ];
+
+    #[test]
+    fn vec_game_next() {
+        let mut game = VecGame::new(9);
+        (0..25).for_each(|_| game.next());
+        assert_eq!(game.scores.iter().max(), Some(&32));
+        assert_eq!(game.scores[4], 32);
+    }
+
+    #[test]
+    fn list_game_next() {
+        for &(rules, score) in EXAMPLES {
+            let rules = Day9::parse(rules).unwrap();
+            let mut game = ListGame::new(rules.players);
+            (0..rules.turns).for_each(|_| game.next());
+            assert_eq!(game.scores.into_iter().max(), Some(score));
+        }
+    }
+
+    #[test]
+    fn part1_examples() {
+        for &(rules, score) in EXAMPLES {
+            let winner = Day9::part1(&Day9::parse(rules).unwrap()).unwrap();
+            assert_eq!(winner.score, score, "{}", rules);
+        }
+    }
+
+    #[test]
+    fn part2_example() {
+        // Part 2 is part 1 with a hundred times more marbles
+        let rules = Day9::parse(EXAMPLES[0].0).unwrap();
+        let longer = Rules {
+            players: rules.players,
+            turns: rules.turns * MARBLE_FACTOR.get(),
+        };
+        let winner = Day9::part2(&rules).unwrap();
+        assert_eq!(winner.score, Day9::part1(&longer).unwrap().score);
+    }
+}
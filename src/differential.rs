@@ -0,0 +1,134 @@
+//! Differential testing: checks that two implementations of the same thing
+//! agree on randomly generated inputs.
+//!
+//! When they disagree, the input is shrunk for as long as they keep
+//! disagreeing, so the reported input is as small as possible.
+
+use std::fmt::{self, Debug, Display};
+
+use crate::rng::Rng;
+
+/// An input that can propose smaller variants of itself.
+pub trait Shrink: Sized {
+    /// Candidates that are strictly smaller than `self`, most promising first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+/// Two implementations gave different results for `input`.
+pub struct Disagreement<I, T> {
+    pub seed: u64,
+    /// Index of the generated input that first disagreed.
+    pub case: usize,
+    /// The minimized input.
+    pub input: I,
+    pub left: T,
+    pub right: T,
+}
+
+impl<I: Display, T: Debug> Display for Disagreement<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "implementations disagree on case {} of seed {}",
+            self.case, self.seed
+        )?;
+        writeln!(f, "  left:  {:?}", self.left)?;
+        writeln!(f, "  right: {:?}", self.right)?;
+        writeln!(f, "minimized input:")?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Runs `left` and `right` on `cases` inputs from `generate`, returning the
+/// first disagreement after minimizing its input.
+pub fn check<I, T>(
+    seed: u64,
+    cases: usize,
+    generate: impl Fn(&mut Rng) -> I,
+    left: impl Fn(&I) -> T,
+    right: impl Fn(&I) -> T,
+) -> Result<(), Disagreement<I, T>>
+where
+    I: Shrink,
+    T: PartialEq,
+{
+    let mut rng = Rng::new(seed);
+    let disagree = |input: &I| left(input) != right(input);
+    for case in 0..cases {
+        let input = generate(&mut rng);
+        if disagree(&input) {
+            let input = minimize(input, disagree);
+            return Err(Disagreement {
+                seed,
+                case,
+                left: left(&input),
+                right: right(&input),
+                input,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Shrinks `input` greedily while it still `fails`.
+pub fn minimize<I: Shrink>(mut input: I, fails: impl Fn(&I) -> bool) -> I {
+    'shrink: loop {
+        for candidate in input.shrink() {
+            if fails(&candidate) {
+                input = candidate;
+                continue 'shrink;
+            }
+        }
+        return input;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Number(u64);
+
+    impl Display for Number {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl Shrink for Number {
+        fn shrink(&self) -> Vec<Self> {
+            match self.0 {
+                0 => vec![],
+                n => vec![Number(n / 2), Number(n - 1)],
+            }
+        }
+    }
+
+    #[test]
+    fn agreeing_implementations_pass() {
+        let result = check(
+            1,
+            100,
+            |rng| Number(rng.below(1000)),
+            |n| n.0 * 2,
+            |n| n.0 + n.0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn disagreement_is_minimized() {
+        let disagreement = check(
+            1,
+            100,
+            |rng| Number(rng.below(1000)),
+            |n| n.0.min(37),
+            |n| n.0,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(disagreement.input, Number(38));
+        assert_eq!((disagreement.left, disagreement.right), (37, 38));
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+
+/// An error found while parsing a puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    /// The offending token, or `end of line`/`end of input`.
+    pub found: String,
+    /// The full offending line, for diagnostics.
+    pub text: String,
+}
+
+impl ParseError {
+    /// Renders the error like a compiler diagnostic, pointing at the offending
+    /// column of the line read from `origin`.
+    pub fn diagnostic(&self, origin: &str) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        // Point at the token itself, without the quotes around punctuation
+        let width = if self.found.starts_with("end of") {
+            1
+        } else {
+            self.found.trim_matches('`').chars().count()
+        };
+        let marker = "^".repeat(width.clamp(1, 80));
+        format!(
+            "error: expected {}, found {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}\n",
+            self.expected,
+            self.found,
+            gutter,
+            origin,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            self.text,
+            gutter,
+            " ".repeat(self.column.saturating_sub(1)),
+            marker
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day{} line {}, column {}: expected {}, found {}",
+            self.day, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An error within a single line, before it is located in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineError {
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl LineError {
+    pub fn new(column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        debug_assert!(column >= 1, "columns are 1-based");
+        LineError {
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Locates the error at 0-based `index` among the lines of day `day`'s input.
+    pub fn at(self, day: u32, index: usize, text: &str) -> ParseError {
+        ParseError {
+            day,
+            line: index + 1,
+            column: self.column,
+            expected: self.expected,
+            found: self.found,
+            text: text.to_string(),
+        }
+    }
+}
@@ -0,0 +1,758 @@
+//! Random puzzle inputs, generated from a seeded [`Rng`] so they can be reproduced.
+//!
+//! Inputs stay structured until printed with `Display`, which writes them in the
+//! puzzle's format, so that they can be shrunk by the differential tests.
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+use crate::differential::Shrink;
+use crate::ocr;
+use crate::rng::Rng;
+
+/// Input for `day` with about `size` elements (lines, claims, steps, nodes…),
+/// or `None` if there is no generator for the day.
+pub fn input(day: u32, rng: &mut Rng, size: usize) -> Option<String> {
+    Some(match day {
+        1 => frequency_changes(rng, size),
+        2 => box_ids(rng, size),
+        3 => claims(rng, size),
+        4 => guard_records(rng, size),
+        5 => polymer(rng, size),
+        6 => coordinates(rng, size),
+        7 => dependencies(rng, size),
+        8 => license(rng, size),
+        9 => Marbles::generate(rng, size).to_string(),
+        10 => StarField::message(rng, size).to_string(),
+        11 => format!("{}\n", 1 + rng.index(100 * size.max(1))),
+        12 => pots(rng, size),
+        13 => TrackMap::crossing(rng, size).to_string(),
+        14 => format!("{}\n", rng.index(1000 * size + 1)),
+        _ => return None,
+    })
+}
+
+/// Day 1: `size` frequency changes adding up to zero, so that some frequency
+/// is always reached twice.
+pub fn frequency_changes(rng: &mut Rng, size: usize) -> String {
+    let mut changes: Vec<i64> = (1..size).map(|_| rng.range(-100_000..100_000)).collect();
+    changes.push(-changes.iter().sum::<i64>());
+    changes
+        .into_iter()
+        .map(|change| format!("{:+}\n", change))
+        .collect()
+}
+
+fn lowercase(rng: &mut Rng) -> char {
+    (b'a' + rng.below(26) as u8) as char
+}
+
+/// Day 2: `size` box ids, two of which differ by a single letter.
+pub fn box_ids(rng: &mut Rng, size: usize) -> String {
+    let mut ids: Vec<Vec<char>> = (0..size)
+        .map(|_| (0..26).map(|_| lowercase(rng)).collect())
+        .collect();
+    if size >= 2 {
+        let mut similar = ids[rng.index(size)].clone();
+        let index = rng.index(similar.len());
+        let letter = similar[index];
+        while similar[index] == letter {
+            similar[index] = lowercase(rng);
+        }
+        ids[rng.index(size)] = similar;
+    }
+    ids.into_iter()
+        .map(|id| id.into_iter().chain(Some('\n')).collect::<String>())
+        .collect()
+}
+
+/// Day 3: `size` claims on a 1000 inch square fabric.
+pub fn claims(rng: &mut Rng, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let (width, height) = (1 + rng.index(29), 1 + rng.index(29));
+            format!(
+                "#{} @ {},{}: {}x{}\n",
+                id,
+                rng.index(1000 - width),
+                rng.index(1000 - height),
+                width,
+                height
+            )
+        })
+        .collect()
+}
+
+/// Day 4: `size` shifts, in random order as in the puzzle.
+///
+/// Guards start their shift before or just after midnight (in which case the
+/// shift belongs to the next day) and only sleep between 00:00 and 00:59.
+pub fn guard_records(rng: &mut Rng, size: usize) -> String {
+    const MONTH_LENGTHS: [usize; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let guards: Vec<_> = (0..=size / 4).map(|_| 1 + rng.index(3500)).collect();
+    let (mut year, mut month, mut day) = (1518, 1 + rng.index(12), 1);
+    let mut records = Vec::new();
+    for _ in 0..size {
+        let previous = format!("{}-{:02}-{:02}", year, month, day);
+        day += 1;
+        if day > MONTH_LENGTHS[month - 1] {
+            day = 1;
+            month += 1;
+            if month > 12 {
+                month = 1;
+                year += 1;
+            }
+        }
+        let date = format!("{}-{:02}-{:02}", year, month, day);
+        let guard = rng.choose(&guards);
+        let shift = if rng.bool() {
+            format!("[{} 23:{:02}]", previous, 45 + rng.index(15))
+        } else {
+            format!("[{} 00:{:02}]", date, rng.index(5))
+        };
+        records.push(format!("{} Guard #{} begins shift", shift, guard));
+        let mut minute = 5 + rng.index(10);
+        while minute < 59 && rng.chance(3, 4) {
+            let asleep = minute + rng.index((59 - minute) / 2 + 1);
+            let awake = asleep + 1 + rng.index(59 - asleep);
+            records.push(format!("[{} 00:{:02}] falls asleep", date, asleep));
+            records.push(format!("[{} 00:{:02}] wakes up", date, awake));
+            minute = awake + 1;
+        }
+    }
+    rng.shuffle(&mut records);
+    records.into_iter().map(|record| record + "\n").collect()
+}
+
+/// Day 5: a polymer of `size` units.
+pub fn polymer(rng: &mut Rng, size: usize) -> String {
+    let mut polymer: String = (0..size)
+        .map(|_| {
+            let unit = lowercase(rng);
+            if rng.bool() {
+                unit.to_ascii_uppercase()
+            } else {
+                unit
+            }
+        })
+        .collect();
+    polymer.push('\n');
+    polymer
+}
+
+/// Day 6: `size` distinct coordinates.
+pub fn coordinates(rng: &mut Rng, size: usize) -> String {
+    let extent = 10 + 8 * size as u64;
+    let mut seen = HashSet::new();
+    let mut coordinates = String::new();
+    while seen.len() < size {
+        let coordinate = (rng.below(extent), rng.below(extent));
+        if seen.insert(coordinate) {
+            coordinates += &format!("{}, {}\n", coordinate.0, coordinate.1);
+        }
+    }
+    coordinates
+}
+
+/// Day 7: dependencies between up to `size` steps (at most 26), without cycles.
+///
+/// Steps are ordered randomly and only depend on earlier steps; every step after
+/// the first depends on at least one other, so they all appear.
+pub fn dependencies(rng: &mut Rng, size: usize) -> String {
+    let mut steps: Vec<char> = ('A'..='Z').collect();
+    rng.shuffle(&mut steps);
+    steps.truncate(size.clamp(2, 26));
+    let mut dependencies = BTreeSet::new();
+    for index in 1..steps.len() {
+        dependencies.insert((steps[rng.index(index)], steps[index]));
+        for requirement in steps[..index].iter() {
+            if rng.chance(1, 4) {
+                dependencies.insert((*requirement, steps[index]));
+            }
+        }
+    }
+    let mut lines: Vec<_> = dependencies
+        .into_iter()
+        .map(|(requirement, step)| {
+            format!(
+                "Step {} must be finished before step {} can begin.\n",
+                requirement, step
+            )
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+/// Day 8: a license tree of `size` nodes, at most 50 deep.
+pub fn license(rng: &mut Rng, size: usize) -> String {
+    fn node(rng: &mut Rng, budget: &mut usize, depth: usize, entries: &mut Vec<usize>) {
+        let children = if depth < 50 && *budget > 0 {
+            1 + rng.index((*budget).min(5))
+        } else {
+            0
+        };
+        *budget -= children;
+        let metadata = 1 + rng.index(3);
+        entries.push(children);
+        entries.push(metadata);
+        for _ in 0..children {
+            node(rng, budget, depth + 1, entries);
+        }
+        for _ in 0..metadata {
+            entries.push(1 + rng.index(children + 2));
+        }
+    }
+    let mut entries = Vec::new();
+    node(rng, &mut size.saturating_sub(1), 0, &mut entries);
+    let entries: Vec<_> = entries.iter().map(usize::to_string).collect();
+    entries.join(" ") + "\n"
+}
+
+/// Day 12: an initial state of `size` pots and a rule for each of the 32 patterns.
+///
+/// Empty pots stay empty, otherwise the row would fill infinitely.
+pub fn pots(rng: &mut Rng, size: usize) -> String {
+    let pot = |plant| if plant { '#' } else { '.' };
+    let initial: String = (0..size.max(1)).map(|_| pot(rng.bool())).collect();
+    let mut rules: Vec<_> = (0..32u32)
+        .map(|pattern| {
+            let pattern: String = (0..5)
+                .rev()
+                .map(|bit| pot(pattern >> bit & 1 == 1))
+                .collect();
+            let plant = pattern != "....." && rng.bool();
+            format!("{} => {}\n", pattern, pot(plant))
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+    format!("initial state: {}\n\n{}", initial, rules.concat())
+}
+
+/// Day 9: a marble game.
+#[derive(Clone, Debug)]
+pub struct Marbles {
+    pub players: usize,
+    pub last_marble: usize,
+}
+
+impl Marbles {
+    /// Up to `size` players, with up to `25 * size` marbles.
+    pub fn generate(rng: &mut Rng, size: usize) -> Marbles {
+        Marbles {
+            players: 1 + rng.index(size.max(1)),
+            last_marble: rng.index(25 * size + 1),
+        }
+    }
+}
+
+impl fmt::Display for Marbles {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} players; last marble is worth {} points",
+            self.players, self.last_marble
+        )
+    }
+}
+
+impl Shrink for Marbles {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+        for players in [self.players / 2, self.players - 1] {
+            if players >= 1 && players < self.players {
+                candidates.push(Marbles { players, ..*self });
+            }
+        }
+        for last_marble in [self.last_marble / 2, self.last_marble.saturating_sub(1)] {
+            if last_marble < self.last_marble {
+                candidates.push(Marbles {
+                    last_marble,
+                    ..*self
+                });
+            }
+        }
+        candidates
+    }
+}
+
+/// Day 10: stars that meet at `(x, y)` after `time` seconds.
+#[derive(Clone, Debug)]
+pub struct StarField {
+    /// `((x, y), (dx, dy))` for each star.
+    pub stars: Vec<((i32, i32), (i32, i32))>,
+    pub time: i32,
+}
+
+impl StarField {
+    /// Up to `size` stars, meeting in a small area after up to `5 * size` seconds.
+    pub fn generate(rng: &mut Rng, size: usize) -> StarField {
+        let stars = (0..=rng.index(size.max(1)))
+            .map(|_| {
+                let mut coordinate = |range| rng.range(range) as i32;
+                (
+                    (coordinate(0..8), coordinate(0..8)),
+                    (coordinate(-3..4), coordinate(-3..4)),
+                )
+            })
+            .collect();
+        StarField {
+            stars,
+            time: rng.index(5 * size + 1) as i32,
+        }
+    }
+}
+
+impl StarField {
+    /// Stars spelling up to `size` (at most 10) letters after up to 10000 seconds.
+    pub fn message(rng: &mut Rng, size: usize) -> StarField {
+        let letters: Vec<char> = ocr::letters().collect();
+        let pixels = loop {
+            let text: String = (0..size.clamp(1, 10))
+                .map(|_| *rng.choose(&letters))
+                .collect();
+            let pixels = ocr::draw(&text).unwrap();
+            // Letters are recognised within the bounding box of the stars
+            let last = pixels[0].len() - 1;
+            if pixels.iter().any(|row| row[0]) && pixels.iter().any(|row| row[last]) {
+                break pixels;
+            }
+        };
+        let mut stars = Vec::new();
+        for (y, row) in pixels.iter().enumerate() {
+            for (x, _) in row.iter().enumerate().filter(|(_, &lit)| lit) {
+                let mut velocity = || rng.range(-5..6) as i32;
+                stars.push(((x as i32, y as i32), (velocity(), velocity())));
+            }
+        }
+        StarField {
+            stars,
+            time: 1 + rng.index(10000) as i32,
+        }
+    }
+}
+
+impl fmt::Display for StarField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &((x, y), (dx, dy)) in self.stars.iter() {
+            writeln!(
+                f,
+                "position=<{:>3}, {:>3}> velocity=<{:>2}, {:>2}>",
+                x - dx * self.time,
+                y - dy * self.time,
+                dx,
+                dy
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Shrink for StarField {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+        if self.stars.len() > 1 {
+            for index in 0..self.stars.len() {
+                let mut stars = self.stars.clone();
+                stars.remove(index);
+                candidates.push(StarField {
+                    stars,
+                    time: self.time,
+                });
+            }
+        }
+        for time in [self.time / 2, self.time - 1] {
+            if time >= 0 && time < self.time {
+                candidates.push(StarField {
+                    stars: self.stars.clone(),
+                    time,
+                });
+            }
+        }
+        candidates
+    }
+}
+
+type Cell = (usize, usize);
+
+/// A rectangular loop of track with its top-left corner at `(x, y)`. Carts are
+/// given by their index along the loop (clockwise from the top-left corner) and
+/// whether they go clockwise.
+#[derive(Clone, Debug)]
+pub struct TrackLoop {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+    pub carts: Vec<(usize, bool)>,
+}
+
+impl TrackLoop {
+    fn length(&self) -> usize {
+        2 * (self.width - 1) + 2 * (self.height - 1)
+    }
+
+    /// Whether a cart at `index` would be at least two tracks away from the others.
+    fn is_free(&self, index: usize) -> bool {
+        let length = self.length();
+        !self.is_corner(index)
+            && self.carts.iter().all(|&(other, _)| {
+                let distance = (index + length - other) % length;
+                distance >= 2 && distance <= length - 2
+            })
+    }
+
+    fn is_corner(&self, index: usize) -> bool {
+        let (w, h) = (self.width - 1, self.height - 1);
+        index == 0 || index == w || index == w + h || index == 2 * w + h
+    }
+
+    /// Position of the track at `index`, and the cart facing clockwise or not there.
+    fn cart(&self, index: usize, clockwise: bool) -> ((usize, usize), char) {
+        let (w, h) = (self.width - 1, self.height - 1);
+        let ((x, y), cw, ccw) = if index < w {
+            ((index, 0), '>', '<')
+        } else if index < w + h {
+            ((w, index - w), 'v', '^')
+        } else if index < 2 * w + h {
+            ((w - (index - w - h), h), '<', '>')
+        } else {
+            ((0, h - (index - 2 * w - h)), '^', 'v')
+        };
+        ((self.x + x, self.y + y), if clockwise { cw } else { ccw })
+    }
+
+    /// Clockwise carts minus counter-clockwise ones.
+    fn imbalance(&self) -> isize {
+        self.carts
+            .iter()
+            .map(|&(_, clockwise)| if clockwise { 1 } else { -1 })
+            .sum()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.width >= 3
+            && self.height >= 3
+            && self
+                .carts
+                .iter()
+                .enumerate()
+                .all(|(position, &(index, _))| {
+                    let others = TrackLoop {
+                        carts: self.carts[position + 1..].to_vec(),
+                        ..*self
+                    };
+                    index < self.length() && others.is_free(index)
+                })
+    }
+}
+
+/// Day 13: loops of track.
+///
+/// Loops either stand side by side, or cross each other at right angles only,
+/// so every cart stays on the tracks whichever way it turns.
+#[derive(Clone, Debug)]
+pub struct TrackMap {
+    pub loops: Vec<TrackLoop>,
+}
+
+impl TrackMap {
+    /// Up to 3 loops side by side, of up to `size + 3` tracks per side.
+    ///
+    /// Carts are at least two tracks apart, so they only crash into carts going
+    /// the other way: with a single cart more in one direction than the other,
+    /// there is always one cart left.
+    pub fn generate(rng: &mut Rng, size: usize) -> TrackMap {
+        let count = 1 + rng.index(3);
+        let mut x = 0;
+        let loops = (0..count)
+            .map(|index| {
+                let mut track = TrackLoop {
+                    x,
+                    y: 0,
+                    width: 3 + rng.index(size + 1),
+                    height: 3 + rng.index(size + 1),
+                    carts: Vec::new(),
+                };
+                x += track.width + 1;
+                let mut free: Vec<_> = (0..track.length())
+                    .filter(|&index| !track.is_corner(index))
+                    .collect();
+                rng.shuffle(&mut free);
+                let mut place = |track: &mut TrackLoop, clockwise| {
+                    let index = free.iter().position(|&index| track.is_free(index))?;
+                    track.carts.push((free.swap_remove(index), clockwise));
+                    Some(())
+                };
+                if index == 0 {
+                    let clockwise = rng.bool();
+                    place(&mut track, clockwise);
+                }
+                for _ in 0..rng.index(3) {
+                    let carts = track.carts.len();
+                    if place(&mut track, true)
+                        .and_then(|_| place(&mut track, false))
+                        .is_none()
+                    {
+                        track.carts.truncate(carts);
+                        break;
+                    }
+                }
+                track
+            })
+            .collect();
+        TrackMap { loops }
+    }
+
+    /// About `size / 4` loops crossing each other, with an odd number of carts.
+    ///
+    /// Corners of every loop are on distinct even rows and columns, so loops
+    /// never touch except where they cross.
+    pub fn crossing(rng: &mut Rng, size: usize) -> TrackMap {
+        let count = 1 + rng.index((size / 4).max(1));
+        let coordinates = |rng: &mut Rng| {
+            let mut lines: Vec<_> = (0..2 * count + size / 2).map(|line| 2 * line).collect();
+            rng.shuffle(&mut lines);
+            lines.truncate(2 * count);
+            lines
+        };
+        let (columns, rows) = (coordinates(rng), coordinates(rng));
+        let left_edge = *columns.iter().min().unwrap();
+        let top_edge = *rows.iter().min().unwrap();
+        let mut map = TrackMap {
+            loops: (0..count)
+                .map(|index| {
+                    let (left, right) = (columns[2 * index], columns[2 * index + 1]);
+                    let (top, bottom) = (rows[2 * index], rows[2 * index + 1]);
+                    TrackLoop {
+                        x: left.min(right) - left_edge,
+                        y: top.min(bottom) - top_edge,
+                        width: left.max(right) - left.min(right) + 1,
+                        height: top.max(bottom) - top.min(bottom) + 1,
+                        carts: Vec::new(),
+                    }
+                })
+                .collect(),
+        };
+        let crossings = map.crossings();
+        let carts = 1 + 2 * rng.index(size / 8 + 1);
+        let mut placed = 0;
+        for _ in 0..100 * carts {
+            if placed == carts {
+                break;
+            }
+            let track = &mut map.loops[rng.index(count)];
+            let index = rng.index(track.length());
+            let clockwise = rng.bool();
+            if track.is_free(index) && !crossings.contains(&track.cart(index, clockwise).0) {
+                track.carts.push((index, clockwise));
+                placed += 1;
+            }
+        }
+        if placed.is_multiple_of(2) {
+            if let Some(track) = map.loops.iter_mut().find(|track| !track.carts.is_empty()) {
+                track.carts.pop();
+            }
+        }
+        map
+    }
+
+    /// Tracks of each loop, split into horizontal and vertical ones (corners excluded).
+    fn tracks(&self) -> (HashSet<Cell>, HashSet<Cell>) {
+        let (mut horizontal, mut vertical) = (HashSet::new(), HashSet::new());
+        for track in self.loops.iter() {
+            let (right, bottom) = (track.x + track.width - 1, track.y + track.height - 1);
+            for x in track.x + 1..right {
+                horizontal.insert((x, track.y));
+                horizontal.insert((x, bottom));
+            }
+            for y in track.y + 1..bottom {
+                vertical.insert((track.x, y));
+                vertical.insert((right, y));
+            }
+        }
+        (horizontal, vertical)
+    }
+
+    fn crossings(&self) -> HashSet<Cell> {
+        let (horizontal, vertical) = self.tracks();
+        horizontal.intersection(&vertical).copied().collect()
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.loops.is_empty()
+            && self.loops.iter().all(TrackLoop::is_valid)
+            && self
+                .loops
+                .iter()
+                .map(|track| track.imbalance().abs())
+                .sum::<isize>()
+                == 1
+    }
+}
+
+impl fmt::Display for TrackMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let height = self
+            .loops
+            .iter()
+            .map(|track| track.y + track.height)
+            .max()
+            .unwrap_or(0);
+        let width = self
+            .loops
+            .iter()
+            .map(|track| track.x + track.width)
+            .max()
+            .unwrap_or(0);
+        let mut rows = vec![vec![' '; width]; height];
+        let (horizontal, vertical) = self.tracks();
+        for &(x, y) in horizontal.iter() {
+            rows[y][x] = if vertical.contains(&(x, y)) { '+' } else { '-' };
+        }
+        for &(x, y) in vertical.difference(&horizontal) {
+            rows[y][x] = '|';
+        }
+        for track in self.loops.iter() {
+            let (right, bottom) = (track.x + track.width - 1, track.y + track.height - 1);
+            rows[track.y][track.x] = '/';
+            rows[track.y][right] = '\\';
+            rows[bottom][track.x] = '\\';
+            rows[bottom][right] = '/';
+            for &(index, clockwise) in track.carts.iter() {
+                let ((x, y), cart) = track.cart(index, clockwise);
+                rows[y][x] = cart;
+            }
+        }
+        for row in rows {
+            writeln!(f, "{}", row.into_iter().collect::<String>().trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+impl Shrink for TrackMap {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+        for index in 0..self.loops.len() {
+            let mut loops = self.loops.clone();
+            loops.remove(index);
+            candidates.push(TrackMap { loops });
+        }
+        for (index, track) in self.loops.iter().enumerate() {
+            for (first, &(_, clockwise)) in track.carts.iter().enumerate() {
+                for second in first + 1..track.carts.len() {
+                    if track.carts[second].1 != clockwise {
+                        let mut loops = self.loops.clone();
+                        loops[index].carts.remove(second);
+                        loops[index].carts.remove(first);
+                        candidates.push(TrackMap { loops });
+                    }
+                }
+            }
+            let mut loops = self.loops.clone();
+            loops[index].width -= 1;
+            candidates.push(TrackMap { loops });
+            let mut loops = self.loops.clone();
+            loops[index].height -= 1;
+            candidates.push(TrackMap { loops });
+        }
+        candidates.retain(TrackMap::is_valid);
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "week2")]
+    use crate::day10::Day10;
+    #[cfg(feature = "week2")]
+    use crate::day13::{Day13, Direction};
+    #[cfg(feature = "week1")]
+    use crate::day4::Day4;
+    #[cfg(feature = "week1")]
+    use crate::day7::Day7;
+    use crate::runner;
+    #[cfg(any(feature = "week1", feature = "week2"))]
+    use crate::Solution;
+
+    #[test]
+    fn inputs_parse() {
+        for entry in runner::DAYS {
+            for seed in 0..10 {
+                for &size in &[1, 10, 50] {
+                    // Days started with `aoc new` have no generator yet
+                    let Some(input) = input(entry.day, &mut Rng::new(seed), size) else {
+                        continue;
+                    };
+                    if let Err(e) = entry.parse(&input) {
+                        panic!("seed {} size {}: {}\n{}", seed, size, e, input);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "week1")]
+    fn guard_records_do_not_depend_on_order() {
+        let records = guard_records(&mut Rng::new(4), 100);
+        let mut sorted: Vec<_> = records.lines().collect();
+        sorted.sort_unstable();
+        let events = Day4::parse(&records).unwrap();
+        assert_eq!(events, Day4::parse(&sorted.join("\n")).unwrap());
+        let asleep: usize = events.values().flatten().map(|range| range.len()).sum();
+        assert!(asleep > 0);
+    }
+
+    #[test]
+    #[cfg(feature = "week1")]
+    fn dependencies_have_no_cycles() {
+        for seed in 0..20 {
+            let dependencies = Day7::parse(&dependencies(&mut Rng::new(seed), 26)).unwrap();
+            let steps: BTreeSet<_> = dependencies.iter().flat_map(|&(a, b)| vec![a, b]).collect();
+            let order = Day7::part1(&dependencies).unwrap();
+            assert_eq!(order.len(), steps.len());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "week2")]
+    fn messages_are_recognized() {
+        for seed in 0..10 {
+            let field = StarField::message(&mut Rng::new(seed), 8);
+            let stars = Day10::parse(&field.to_string()).unwrap();
+            assert_eq!(Day10::part2(&stars), Some(field.time as usize));
+            let message = Day10::part1(&stars).unwrap();
+            assert!(
+                ocr::recognize(&message.pixels()).is_some(),
+                "{}",
+                message.render()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "week2")]
+    fn carts_stay_on_tracks() {
+        for seed in 0..20 {
+            let map = TrackMap::crossing(&mut Rng::new(seed), 40);
+            let mine = Day13::parse(&map.to_string()).unwrap();
+            assert_eq!(mine.carts.len() % 2, 1);
+            // Every connection leads to a track connected back
+            for (position, connections) in mine.tracks.iter() {
+                let Some(connections) = connections else {
+                    continue;
+                };
+                for direction in Direction::ALL {
+                    if connections.has_direction(direction) {
+                        let track = mine.tracks[position.step(direction)].as_ref();
+                        let back = direction.reverse();
+                        assert!(track.unwrap().has_direction(back), "{}", map);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//! A rectangular grid of cells, addressed by [`Point`]s or `(x, y)` tuples with `y`
+//! growing downwards, as in the puzzle pictures.
+//!
+//! ```
+//! use aoc2018::grid::Grid;
+//!
+//! let mut grid = Grid::parse(0, "#..\n.#.", |c| Ok(c == '#')).unwrap();
+//! assert_eq!((grid.width(), grid.height()), (3, 2));
+//! grid.rect_mut((1, 0), (2, 1)).for_each(|cell| *cell = !*cell);
+//! assert_eq!(grid.display(|&on| if on { '#' } else { '.' }).to_string(), "###\n..#\n");
+//! ```
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{LineError, ParseError};
+use crate::parse;
+use crate::point::Point;
+
+/// Offsets of the 4 orthogonal neighbours, in reading order.
+const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the 8 neighbours including diagonals, in reading order.
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Cells row by row.
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with each cell set to `f` of its point.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point<usize>) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Reads a block of characters, one row per line, with `cell` naming what it
+    /// expected when it rejects a character. Lines shorter than the longest one
+    /// are read as if padded with spaces.
+    pub fn parse(
+        day: u32,
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut cells = Vec::new();
+        let mut first = None;
+        for (y, line) in input.lines().enumerate() {
+            let mut chars = line.chars();
+            for x in 0..width {
+                let c = chars.next();
+                match cell(c.unwrap_or(' ')) {
+                    Ok(value) => cells.push(value),
+                    Err(expected) => {
+                        let found = match c {
+                            Some(c) => format!("`{}`", c),
+                            None => String::from("end of line"),
+                        };
+                        let e =
+                            parse::report(LineError::new(x + 1, expected, found).at(day, y, line));
+                        if !parse::collecting() {
+                            return Err(e);
+                        }
+                        // One error per line is enough
+                        first.get_or_insert(e);
+                        break;
+                    }
+                }
+            }
+        }
+        if let Some(e) = first {
+            return Err(e);
+        }
+        Ok(Grid {
+            width,
+            height: input.lines().count(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: impl Into<Point<usize>>) -> bool {
+        let point = point.into();
+        point.x < self.width && point.y < self.height
+    }
+
+    fn index_of(&self, point: impl Into<Point<usize>>) -> Option<usize> {
+        let point = point.into();
+        if self.contains(point) {
+            Some(point.y * self.width + point.x)
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `point`, or `None` outside of the grid.
+    pub fn get(&self, point: impl Into<Point<usize>>) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: impl Into<Point<usize>>) -> Option<&mut T> {
+        self.index_of(point)
+            .map(move |index| &mut self.cells[index])
+    }
+
+    /// Every point of the grid, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell, in reading order.
+    pub fn cells(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Every cell with its point, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point<usize>, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    /// Cells of row `y`, from left to right.
+    pub fn row(&self, y: usize) -> std::slice::Iter<'_, T> {
+        assert!(y < self.height, "row {} out of {}", y, self.height);
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> std::slice::IterMut<'_, T> {
+        assert!(y < self.height, "row {} out of {}", y, self.height);
+        self.cells[y * self.width..(y + 1) * self.width].iter_mut()
+    }
+
+    /// Cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of {}", x, self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn column_mut(&mut self, x: usize) -> impl Iterator<Item = &mut T> {
+        assert!(x < self.width, "column {} out of {}", x, self.width);
+        self.cells[x..].iter_mut().step_by(self.width)
+    }
+
+    /// Cells of the rectangle between two corners, both included, row by row.
+    pub fn rect(
+        &self,
+        top_left: impl Into<Point<usize>>,
+        bottom_right: impl Into<Point<usize>>,
+    ) -> impl Iterator<Item = &T> {
+        let (top_left, bottom_right) = self.check_rect(top_left.into(), bottom_right.into());
+        self.cells
+            .chunks(self.width)
+            .take(bottom_right.y + 1)
+            .skip(top_left.y)
+            .flat_map(move |row| &row[top_left.x..=bottom_right.x])
+    }
+
+    pub fn rect_mut(
+        &mut self,
+        top_left: impl Into<Point<usize>>,
+        bottom_right: impl Into<Point<usize>>,
+    ) -> impl Iterator<Item = &mut T> {
+        let (top_left, bottom_right) = self.check_rect(top_left.into(), bottom_right.into());
+        self.cells
+            .chunks_mut(self.width)
+            .take(bottom_right.y + 1)
+            .skip(top_left.y)
+            .flat_map(move |row| &mut row[top_left.x..=bottom_right.x])
+    }
+
+    fn check_rect(
+        &self,
+        top_left: Point<usize>,
+        bottom_right: Point<usize>,
+    ) -> (Point<usize>, Point<usize>) {
+        assert!(
+            top_left.x <= bottom_right.x
+                && top_left.y <= bottom_right.y
+                && self.contains(bottom_right),
+            "rectangle ({}, {})-({}, {}) out of {}x{}",
+            top_left.x,
+            top_left.y,
+            bottom_right.x,
+            bottom_right.y,
+            self.width,
+            self.height
+        );
+        (top_left, bottom_right)
+    }
+
+    fn neighbours<'a>(
+        &self,
+        point: Point<usize>,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Point<usize>> + 'a {
+        let (width, height) = (self.width, self.height);
+        offsets
+            .iter()
+            .map(move |&(dx, dy)| {
+                Point::new(
+                    point.x.wrapping_add_signed(dx),
+                    point.y.wrapping_add_signed(dy),
+                )
+            })
+            .filter(move |point| point.x < width && point.y < height)
+    }
+
+    /// Points above, left, right and below `point` that are inside the grid.
+    pub fn neighbours4(
+        &self,
+        point: impl Into<Point<usize>>,
+    ) -> impl Iterator<Item = Point<usize>> {
+        self.neighbours(point.into(), &NEIGHBOURS4)
+    }
+
+    /// Points around `point`, diagonals included, that are inside the grid.
+    pub fn neighbours8(
+        &self,
+        point: impl Into<Point<usize>>,
+    ) -> impl Iterator<Item = Point<usize>> {
+        self.neighbours(point.into(), &NEIGHBOURS8)
+    }
+
+    /// Renders the grid one line per row, drawing each cell with `draw`.
+    pub fn display<F: Fn(&T) -> char>(&self, draw: F) -> Render<'_, T, F> {
+        Render { grid: self, draw }
+    }
+
+    fn out_of_bounds(&self, point: Point<usize>) -> ! {
+        panic!(
+            "point ({}, {}) out of {}x{}",
+            point.x, point.y, self.width, self.height
+        )
+    }
+}
+
+impl<T, P: Into<Point<usize>>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: P) -> &T {
+        let point = point.into();
+        match self.index_of(point) {
+            Some(index) => &self.cells[index],
+            None => self.out_of_bounds(point),
+        }
+    }
+}
+
+impl<T, P: Into<Point<usize>>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, point: P) -> &mut T {
+        let point = point.into();
+        match self.index_of(point) {
+            Some(index) => &mut self.cells[index],
+            None => self.out_of_bounds(point),
+        }
+    }
+}
+
+/// A grid drawn one character per cell, see [`Grid::display`].
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    draw: F,
+}
+
+impl<T, F: Fn(&T) -> char> fmt::Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.grid.height {
+            for cell in self.grid.row(y) {
+                write!(f, "{}", (self.draw)(cell))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(width: usize, height: usize) -> Grid<usize> {
+        Grid::from_fn(width, height, |p| p.y * 10 + p.x)
+    }
+
+    #[test]
+    fn indexing_is_bounds_checked() {
+        let mut grid = numbered(3, 2);
+        assert_eq!(grid[(2, 1)], 12);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        grid[(1, 0)] = 99;
+        assert_eq!(grid.get((1, 0)), Some(&99));
+        assert_eq!(grid.points().last(), Some(Point::new(2, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "point (3, 0) out of 3x2")]
+    fn indexing_outside_panics() {
+        let _ = numbered(3, 2)[(3, 0)];
+    }
+
+    #[test]
+    fn rows_columns_and_rects() {
+        let mut grid = numbered(4, 3);
+        assert_eq!(
+            grid.row(1).copied().collect::<Vec<_>>(),
+            vec![10, 11, 12, 13]
+        );
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![2, 12, 22]);
+        assert_eq!(
+            grid.rect((1, 1), (2, 2)).copied().collect::<Vec<_>>(),
+            vec![11, 12, 21, 22]
+        );
+        grid.row_mut(0).for_each(|cell| *cell = 0);
+        grid.column_mut(3).for_each(|cell| *cell = 0);
+        grid.rect_mut((1, 1), (1, 2)).for_each(|cell| *cell = 0);
+        assert_eq!(
+            grid.cells().copied().collect::<Vec<_>>(),
+            vec![0, 0, 0, 0, 10, 0, 12, 0, 20, 0, 22, 0]
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = numbered(3, 3);
+        let values = |points: &mut dyn Iterator<Item = Point<usize>>| {
+            points.map(|point| grid[point]).collect::<Vec<_>>()
+        };
+        assert_eq!(values(&mut grid.neighbours4((1, 1))), vec![1, 10, 12, 21]);
+        assert_eq!(values(&mut grid.neighbours4((0, 0))), vec![1, 10]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(values(&mut grid.neighbours8((2, 2))), vec![11, 12, 21]);
+    }
+
+    #[test]
+    fn parse_pads_short_lines() {
+        let grid = Grid::parse(0, "ab\na\nabc", Ok).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.display(|&c| c).to_string(), "ab \na  \nabc\n");
+    }
+
+    #[test]
+    fn parse_reports_rejected_cells() {
+        let digit = |c: char| c.to_digit(10).ok_or("digit");
+        let error = Grid::parse(11, "12\n3x", digit).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            (error.expected.as_str(), error.found.as_str()),
+            ("digit", "`x`")
+        );
+        let error = Grid::parse(11, "12\n3", digit).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.found, "end of line");
+    }
+}
@@ -0,0 +1,288 @@
+//! A local log of every answer computed by `aoc run`, to see how answers and
+//! runtimes change over time and catch answers changing for the same input.
+//!
+//! Records are stored one per line as
+//! `<unix time> day<N> part<P> <input hash> <commit or -> <time ns> <answer or ->`,
+//! with newlines in answers written as `\n`.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::runner::{DayResult, Part};
+
+const HEADER: &str = "# timestamp day part input_hash commit time_ns answer";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub day: u32,
+    pub part: Part,
+    pub input_hash: u64,
+    /// The commit checked out when the answer was computed, if known.
+    pub commit: Option<String>,
+    pub time: Duration,
+    /// `None` if no solution was found.
+    pub answer: Option<String>,
+}
+
+impl Record {
+    /// Records for every part of `result`, computed from the input hashed as
+    /// `input_hash` at `commit`.
+    pub fn of(result: &DayResult, input_hash: u64, commit: Option<&str>) -> Vec<Record> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        result
+            .parts
+            .iter()
+            .map(|part| Record {
+                timestamp,
+                day: result.day,
+                part: part.part,
+                input_hash,
+                commit: commit.map(String::from),
+                time: part.time,
+                answer: part.answer.clone(),
+            })
+            .collect()
+    }
+
+    pub fn parse(line: &str) -> Result<Record, String> {
+        let error = || format!("invalid history record: {}", line);
+        let fields: Vec<_> = line.splitn(7, ' ').collect();
+        let [timestamp, day, part, input_hash, commit, time, answer] = fields[..] else {
+            return Err(error());
+        };
+        let optional = |field: &str| (field != "-").then(|| field.to_string());
+        Ok(Record {
+            timestamp: timestamp.parse().map_err(|_| error())?,
+            day: day
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(error)?,
+            part: match part {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(error()),
+            },
+            input_hash: u64::from_str_radix(input_hash, 16).map_err(|_| error())?,
+            commit: optional(commit),
+            time: time
+                .parse()
+                .map(Duration::from_nanos)
+                .map_err(|_| error())?,
+            answer: optional(answer).map(|answer| unescape(&answer)),
+        })
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} day{} part{} {:016x} {} {} {}",
+            self.timestamp,
+            self.day,
+            self.part,
+            self.input_hash,
+            self.commit.as_deref().unwrap_or("-"),
+            self.time.as_nanos(),
+            self.answer.as_deref().map_or(String::from("-"), escape)
+        )
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            (c, _) => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// FNV-1a hash of an input, to tell whether it changed between runs.
+pub fn hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Every record in the history at `path`, oldest first; none if it does not exist yet.
+pub fn read(path: &Path) -> Result<Vec<Record>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("{}: {}", path.display(), e)),
+    };
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| Record::parse(line).map_err(|e| format!("line {}: {}", index + 1, e)))
+        .collect()
+}
+
+/// Appends `records` to the history at `path`, creating it if needed.
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for record in records {
+        writeln!(file, "{}", record)?;
+    }
+    Ok(())
+}
+
+/// The last earlier record for the same day, part and input as `record`, if
+/// its answer was different.
+pub fn regression<'a>(history: &'a [Record], record: &Record) -> Option<&'a Record> {
+    history
+        .iter()
+        .rev()
+        .find(|earlier| {
+            (earlier.day, earlier.part, earlier.input_hash)
+                == (record.day, record.part, record.input_hash)
+        })
+        .filter(|earlier| earlier.answer != record.answer)
+}
+
+/// The commit checked out in the git repository holding `dir`, read from
+/// `.git` directly, or `None` outside of a repository.
+pub fn git_commit(dir: &Path) -> Option<String> {
+    let git = dir
+        .ancestors()
+        .map(|dir| dir.join(".git"))
+        .find(|git| git.exists())?;
+    // Worktrees and submodules have a file pointing to the real directory
+    let git = if git.is_file() {
+        let pointer = fs::read_to_string(&git).ok()?;
+        let target = PathBuf::from(pointer.strip_prefix("gitdir:")?.trim());
+        git.parent()?.join(target)
+    } else {
+        git
+    };
+    let head = fs::read_to_string(git.join("HEAD")).ok()?;
+    let head = head.trim();
+    let Some(reference) = head.strip_prefix("ref:").map(str::trim) else {
+        return Some(head.to_string());
+    };
+    if let Ok(commit) = fs::read_to_string(git.join(reference)) {
+        return Some(commit.trim().to_string());
+    }
+    // Refs not written out on their own are packed together
+    let packed = fs::read_to_string(git.join("packed-refs")).ok()?;
+    packed
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .find(|(_, name)| *name == reference)
+        .map(|(commit, _)| commit.to_string())
+}
+
+/// `timestamp` as a UTC date and time, `YYYY-MM-DD hh:mm:ss`.
+pub fn utc(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86400, timestamp % 86400);
+    // Civil date from days since 1970-01-01, counting eras of 400 years
+    // from 0000-03-01 so that leap days end the year
+    let days = days as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn record(answer: Option<&str>) -> Record {
+        Record {
+            timestamp: 1_700_000_000,
+            day: 10,
+            part: Part::One,
+            input_hash: hash("input"),
+            commit: Some(String::from("5fd367e")),
+            time: Duration::from_micros(1500),
+            answer: answer.map(String::from),
+        }
+    }
+
+    #[test]
+    fn records_round_trip() {
+        for answer in [Some("#..#\n#\\n #"), Some("3410"), None] {
+            let record = record(answer);
+            assert_eq!(Record::parse(&record.to_string()), Ok(record));
+        }
+        assert!(Record::parse("1700000000 day10 part3 0 - 5 3").is_err());
+    }
+
+    #[test]
+    fn regressions_compare_the_same_input() {
+        let mut other_input = record(Some("1"));
+        other_input.input_hash = hash("other");
+        let history = vec![record(Some("2")), record(Some("1")), other_input];
+        assert_eq!(regression(&history, &record(Some("1"))), None);
+        assert_eq!(regression(&history, &record(Some("3"))), Some(&history[1]));
+        assert_eq!(regression(&history[..0], &record(Some("3"))), None);
+    }
+
+    #[test]
+    fn utc_dates() {
+        assert_eq!(utc(0), "1970-01-01 00:00:00");
+        assert_eq!(utc(951_782_400), "2000-02-29 00:00:00");
+        assert_eq!(utc(1_700_000_000), "2023-11-14 22:13:20");
+    }
+
+    #[test]
+    fn commit_from_git_directory() {
+        let dir = env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        let git = dir.join(".git");
+        fs::create_dir_all(git.join("refs/heads")).unwrap();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        fs::write(
+            git.join("packed-refs"),
+            "# pack-refs\nabc123 refs/heads/main\n",
+        )
+        .unwrap();
+        assert_eq!(git_commit(&dir.join("src")).as_deref(), Some("abc123"));
+        fs::write(git.join("refs/heads/main"), "def456\n").unwrap();
+        assert_eq!(git_commit(&dir).as_deref(), Some("def456"));
+        fs::write(git.join("HEAD"), "0123abcd\n").unwrap();
+        assert_eq!(git_commit(&dir).as_deref(), Some("0123abcd"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,275 @@
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory holding `dayN.txt` and `answers.txt`.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Resolves the data directory: `$AOC_DATA_DIR` if set, then `./data` if it exists,
+/// then the `data` directory of this crate's checkout.
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(DATA_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    let local = PathBuf::from("data");
+    if local.is_dir() {
+        local
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("data")
+    }
+}
+
+/// Someone's puzzle inputs with their answers: `dayN.txt` and `answers.txt`
+/// in `<data_dir>/<name>`, or in the [`data_dir`] itself for the default set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputSet(Option<String>);
+
+impl InputSet {
+    /// The set in `<data_dir>/<name>`, if `name` is a plain directory name.
+    pub fn named(name: &str) -> Result<InputSet, String> {
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+            return Err(format!("invalid input set name: {}", name));
+        }
+        Ok(InputSet(Some(name.to_string())))
+    }
+
+    /// Every set with answers in `dir`: the default one first if `dir` has its
+    /// own, then the named ones by name.
+    pub fn all_in(dir: &Path) -> io::Result<Vec<InputSet>> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.path().join(ANSWERS_FILE).is_file() {
+                names.extend(entry.file_name().to_str().map(String::from));
+            }
+        }
+        names.sort();
+        let default = dir.join(ANSWERS_FILE).is_file().then(InputSet::default);
+        Ok(default
+            .into_iter()
+            .chain(names.into_iter().map(|name| InputSet(Some(name))))
+            .collect())
+    }
+
+    /// Every set in the [`data_dir`].
+    pub fn all() -> io::Result<Vec<InputSet>> {
+        Self::all_in(&data_dir())
+    }
+
+    /// `default` for the default set.
+    pub fn name(&self) -> &str {
+        self.0.as_deref().unwrap_or("default")
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        match &self.0 {
+            Some(name) => data_dir().join(name),
+            None => data_dir(),
+        }
+    }
+
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        self.dir().join(format!("day{}.txt", day))
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        self.dir().join(ANSWERS_FILE)
+    }
+
+    pub fn read_answers(&self) -> io::Result<String> {
+        let mut answers = String::new();
+        read_file(&self.answers_path(), &mut answers)?;
+        Ok(answers)
+    }
+}
+
+const ANSWERS_FILE: &str = "answers.txt";
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// `dayN.txt` of an input set.
+    Set(InputSet),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Default for Source {
+    /// The default input set.
+    fn default() -> Self {
+        Source::Set(InputSet::default())
+    }
+}
+
+impl Source {
+    /// Interprets a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Reads day `day`'s input, [normalized](normalize).
+    pub fn read(&self, day: u32) -> io::Result<String> {
+        self.read_raw(day).map(|input| normalize(&input))
+    }
+
+    /// Reads day `day`'s input as it is stored.
+    pub fn read_raw(&self, day: u32) -> io::Result<String> {
+        let mut input = String::new();
+        match self {
+            Source::Set(set) => read_file(&set.input_path(day), &mut input)?,
+            Source::File(path) => read_file(path, &mut input)?,
+            Source::Stdin => {
+                io::stdin().read_to_string(&mut input)?;
+            }
+        }
+        Ok(input)
+    }
+
+    /// Describes where day `day`'s input comes from, for diagnostics.
+    pub fn origin(&self, day: u32) -> String {
+        match self {
+            Source::Set(set) => set.input_path(day).display().to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => String::from("<stdin>"),
+        }
+    }
+}
+
+/// The input with `\n` line endings, without a byte order mark, trailing
+/// whitespace or blank lines at the end, and ending with a newline unless empty.
+/// Leading whitespace is kept, as in day 13's maps.
+pub fn normalize(raw: &str) -> String {
+    let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let raw = raw.replace("\r\n", "\n").replace('\r', "\n");
+    let mut input = String::with_capacity(raw.len());
+    for line in raw.lines() {
+        input.push_str(line.trim_end());
+        input.push('\n');
+    }
+    let end = input.trim_end().len();
+    input.truncate(end);
+    if !input.is_empty() {
+        input.push('\n');
+    }
+    input
+}
+
+/// What [`normalize`] changes in `raw`, described for `aoc check-input`.
+pub fn irregularities(raw: &str) -> Vec<String> {
+    let mut found = Vec::new();
+    if raw.starts_with('\u{feff}') {
+        found.push(String::from("starts with a byte order mark"));
+    }
+    let crlf = raw.matches("\r\n").count();
+    if crlf > 0 {
+        found.push(format!("{} line(s) end with CRLF", crlf));
+    }
+    let cr = raw.matches('\r').count() - crlf;
+    if cr > 0 {
+        found.push(format!("{} line(s) end with a lone CR", cr));
+    }
+    let trailing: Vec<_> = raw
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.trim_end_matches('\r') != line.trim_end())
+        .map(|(index, _)| index + 1)
+        .collect();
+    if let Some(first) = trailing.first() {
+        found.push(format!(
+            "{} line(s) have trailing whitespace, first on line {}",
+            trailing.len(),
+            first
+        ));
+    }
+    let blank = raw
+        .lines()
+        .rev()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    if blank > 0 && raw.lines().any(|line| !line.trim().is_empty()) {
+        found.push(format!("{} blank line(s) at the end", blank));
+    }
+    found
+}
+
+fn read_file(path: &Path, input: &mut String) -> io::Result<()> {
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(input))
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    Ok(())
+}
+
+/// `dayN.txt` of the default input set.
+pub fn input_path(day: u32) -> PathBuf {
+    InputSet::default().input_path(day)
+}
+
+pub fn read_input(day: u32) -> io::Result<String> {
+    Source::default().read(day)
+}
+
+/// Benchmark baseline kept next to the inputs.
+pub fn baseline_path() -> PathBuf {
+    data_dir().join("bench_baseline.txt")
+}
+
+/// Log of the answers computed by `aoc run`, kept next to the inputs.
+pub fn history_path() -> PathBuf {
+    data_dir().join("history.txt")
+}
+
+pub fn answers_path() -> PathBuf {
+    InputSet::default().answers_path()
+}
+
+pub fn read_answers() -> io::Result<String> {
+    InputSet::default().read_answers()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_line_endings_and_whitespace() {
+        assert_eq!(normalize("+1\r\n-2 \r\n\r\n\n"), "+1\n-2\n");
+        assert_eq!(normalize("\u{feff}18\r"), "18\n");
+        assert_eq!(normalize("a\rb\n\n  c"), "a\nb\n\n  c\n");
+        assert_eq!(normalize(" \n\n"), "");
+        assert_eq!(normalize("x\r\n\r\ny\r\n"), "x\n\ny\n");
+    }
+
+    #[test]
+    fn describes_irregularities() {
+        assert_eq!(
+            irregularities("+1\r\n-2 \r\n+3\t\r\n\r\n"),
+            vec![
+                "4 line(s) end with CRLF",
+                "2 line(s) have trailing whitespace, first on line 2",
+                "1 blank line(s) at the end",
+            ]
+        );
+        assert!(irregularities("+1\n-2\n").is_empty());
+    }
+
+    #[test]
+    fn finds_sets_with_answers() {
+        let dir = env::temp_dir().join(format!("aoc-sets-{}", std::process::id()));
+        for set in ["bob", "alice", "no-answers"] {
+            fs::create_dir_all(dir.join(set)).unwrap();
+        }
+        for answers in ["answers.txt", "bob/answers.txt", "alice/answers.txt"] {
+            fs::write(dir.join(answers), "day1 3").unwrap();
+        }
+        let sets = InputSet::all_in(&dir).unwrap();
+        let names: Vec<_> = sets.iter().map(InputSet::name).collect();
+        assert_eq!(names, vec!["default", "alice", "bob"]);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(InputSet::named("../elsewhere").is_err());
+    }
+}
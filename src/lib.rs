@@ -0,0 +1,83 @@
+use std::fmt::Display;
+
+use error::ParseError;
+use params::Parameter;
+
+pub mod answers;
+pub mod bench;
+pub mod differential;
+pub mod error;
+pub mod gen;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod json;
+pub mod ocr;
+pub mod params;
+pub mod parse;
+pub mod point;
+pub mod pool;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod simulation;
+pub mod step;
+pub mod trace;
+pub mod watch;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub use input::{read_answers, read_input};
+
+/// A puzzle solution: the input is parsed once and both parts are solved from it.
+///
+/// Parts return `None` when the input has no answer (e.g. no cart survives in day 13).
+pub trait Solution {
+    const DAY: u32;
+    /// Knobs of the puzzle, such as the number of workers in day 7, which
+    /// default to the values in the puzzle statement.
+    const PARAMS: &'static [&'static dyn Parameter] = &[];
+    type Input;
+    type Part1: Answer;
+    type Part2: Answer;
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Option<Self::Part1>;
+    fn part2(input: &Self::Input) -> Option<Self::Part2>;
+}
+
+/// The answer to a part, along with the named intermediate values it was derived from.
+pub trait Answer: Display {
+    fn values(&self) -> Vec<(&'static str, json::Value)> {
+        Vec::new()
+    }
+
+    /// How the answer was derived, one step per line, for `--explain`.
+    /// Defaults to the named values.
+    fn explain(&self) -> Vec<String> {
+        self.values()
+            .into_iter()
+            .map(|(name, value)| format!("{} = {}", name, value))
+            .collect()
+    }
+}
+
+macro_rules! plain_answers {
+    ( $( $t:ty ),* ) => {
+        $( impl Answer for $t {} )*
+    };
+}
+
+plain_answers!(i64, u64, usize, String);
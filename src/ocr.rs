@@ -0,0 +1,173 @@
+//! Recognition of the block letters that day 10's stars spell out.
+
+const HEIGHT: usize = 10;
+const WIDTH: usize = 6;
+const SPACING: usize = 2;
+
+const GLYPHS: &[(char, [&str; HEIGHT])] = &[
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+fn matches(glyph: &[&str; HEIGHT], pixels: &[Vec<bool>], column: usize) -> bool {
+    glyph.iter().zip(pixels).all(|(glyph_row, row)| {
+        glyph_row
+            .chars()
+            .zip(&row[column..column + WIDTH])
+            .all(|(c, &lit)| (c == '#') == lit)
+    })
+}
+
+/// Reads the letters drawn by `pixels`, given as rows trimmed to the bounding box
+/// of the lit pixels. Returns `None` if any letter is not recognised.
+pub fn recognize(pixels: &[Vec<bool>]) -> Option<String> {
+    let width = pixels.first()?.len();
+    if pixels.len() != HEIGHT || !(width + SPACING).is_multiple_of(WIDTH + SPACING) {
+        return None;
+    }
+    (0..(width + SPACING) / (WIDTH + SPACING))
+        .map(|index| {
+            let column = index * (WIDTH + SPACING);
+            GLYPHS
+                .iter()
+                .find(|(_, glyph)| matches(glyph, pixels, column))
+                .map(|(letter, _)| *letter)
+        })
+        .collect()
+}
+
+/// The letters that can be recognised.
+pub fn letters() -> impl Iterator<Item = char> {
+    GLYPHS.iter().map(|(letter, _)| *letter)
+}
+
+/// Draws `text` in block letters, as rows of pixels. Returns `None` if a letter
+/// has no glyph.
+pub fn draw(text: &str) -> Option<Vec<Vec<bool>>> {
+    let glyphs = text
+        .chars()
+        .map(|c| {
+            GLYPHS
+                .iter()
+                .find(|(letter, _)| *letter == c)
+                .map(|(_, glyph)| glyph)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(
+        (0..HEIGHT)
+            .map(|y| {
+                let mut row = Vec::new();
+                for (index, glyph) in glyphs.iter().enumerate() {
+                    if index > 0 {
+                        row.extend([false; SPACING]);
+                    }
+                    row.extend(glyph[y].chars().map(|c| c == '#'));
+                }
+                row
+            })
+            .collect(),
+    )
+}
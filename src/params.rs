@@ -0,0 +1,358 @@
+//! Named puzzle parameters, such as the number of workers in day 7, defaulting
+//! to the values from the puzzle statements.
+//!
+//! Days declare their parameters as constants, list them in
+//! [`Solution::PARAMS`](crate::Solution::PARAMS) and read them while solving:
+//!
+//! ```
+//! use aoc2018::params::Param;
+//!
+//! const WORKERS: Param<usize> = Param::new(7, "workers", 5, "Workers assembling the steps");
+//! assert_eq!(WORKERS.get(), 5);
+//! ```
+//!
+//! Values are overridden within [`scope`], from `--param name=value` or a file
+//! of `[dayN]` sections with `name = value` lines, parsed and checked against
+//! the parameters once by [`Overrides::values`]:
+//!
+//! ```text
+//! # Part 2 of the example
+//! [day7]
+//! workers = 2
+//! base_time = 0
+//! ```
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::runner::Entry;
+
+/// A parameter of day `day`, read with [`Param::get`].
+pub struct Param<T: 'static> {
+    pub day: u32,
+    pub name: &'static str,
+    pub default: T,
+    pub doc: &'static str,
+    /// The smallest value the day can be solved with, if any.
+    pub min: Option<T>,
+    /// The largest value the day can be solved with in reasonable time and
+    /// memory, if any.
+    pub max: Option<T>,
+}
+
+impl<T> Param<T> {
+    pub const fn new(day: u32, name: &'static str, default: T, doc: &'static str) -> Self {
+        Param {
+            day,
+            name,
+            default,
+            doc,
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl<T: Copy> Param<T> {
+    /// The parameter, rejecting overrides below `min`.
+    pub const fn at_least(self, min: T) -> Self {
+        Param {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// The parameter, rejecting overrides above `max`.
+    pub const fn at_most(self, max: T) -> Self {
+        Param {
+            max: Some(max),
+            ..self
+        }
+    }
+}
+
+impl<T: Clone + 'static> Param<T> {
+    /// The value overridden in the current scope, or the default.
+    pub fn get(&self) -> T {
+        VALUES
+            .with(|values| {
+                values
+                    .borrow()
+                    .0
+                    .get(&(self.day, self.name))
+                    .and_then(|value| value.downcast_ref::<T>().cloned())
+            })
+            .unwrap_or_else(|| self.default.clone())
+    }
+}
+
+/// A [`Param`] of any type, for listing parameters and checking overrides.
+pub trait Parameter: Sync {
+    fn name(&self) -> &'static str;
+    fn doc(&self) -> &'static str;
+    /// The name of the type of the values, e.g. `usize`.
+    fn kind(&self) -> &'static str;
+    fn default(&self) -> String;
+    /// The range of values accepted, e.g. `at least 1`, if bounded.
+    fn bounds(&self) -> Option<String>;
+    /// `value` parsed, if it is a value of the parameter's type within its
+    /// bounds.
+    fn parse(&self, value: &str) -> Option<Arc<dyn Any + Send + Sync>>;
+}
+
+impl<T> Parameter for Param<T>
+where
+    T: Clone + Display + FromStr + PartialOrd + Send + Sync + 'static,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn doc(&self) -> &'static str {
+        self.doc
+    }
+
+    fn kind(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn default(&self) -> String {
+        self.default.to_string()
+    }
+
+    fn bounds(&self) -> Option<String> {
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) => Some(format!("from {} to {}", min, max)),
+            (Some(min), None) => Some(format!("at least {}", min)),
+            (None, Some(max)) => Some(format!("at most {}", max)),
+            (None, None) => None,
+        }
+    }
+
+    fn parse(&self, value: &str) -> Option<Arc<dyn Any + Send + Sync>> {
+        let value = value.parse::<T>().ok()?;
+        let within = self.min.as_ref().is_none_or(|min| value >= *min)
+            && self.max.as_ref().is_none_or(|max| value <= *max);
+        within.then(|| Arc::new(value) as Arc<dyn Any + Send + Sync>)
+    }
+}
+
+/// Values given to parameters instead of their defaults, by day and name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Overrides(BTreeMap<(u32, String), String>);
+
+impl Overrides {
+    /// Parses a file of `[dayN]` sections with `name = value` lines, where `#`
+    /// starts a comment.
+    pub fn parse(text: &str) -> Result<Overrides, String> {
+        let mut overrides = Overrides::default();
+        let mut day = None;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let error = |message: String| format!("line {}: {}", index + 1, message);
+            if let Some(section) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+                let number = section
+                    .trim()
+                    .strip_prefix("day")
+                    .and_then(|n| n.parse().ok());
+                day = Some(number.ok_or_else(|| error(format!("invalid section: {}", line)))?);
+                continue;
+            }
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected `name = value`: {}", line)))?;
+            let day = day.ok_or_else(|| error(String::from("expected `[dayN]` first")))?;
+            overrides.set(day, name.trim(), value.trim());
+        }
+        Ok(overrides)
+    }
+
+    /// Sets a parameter of day `day` from `name=value`.
+    pub fn set_arg(&mut self, day: u32, arg: &str) -> Result<(), String> {
+        let (name, value) = arg
+            .split_once('=')
+            .ok_or_else(|| format!("expected name=value: {}", arg))?;
+        self.set(day, name, value);
+        Ok(())
+    }
+
+    pub fn set(&mut self, day: u32, name: &str, value: &str) {
+        self.0.insert((day, name.to_string()), value.to_string());
+    }
+
+    pub fn get(&self, day: u32, name: &str) -> Option<&str> {
+        self.0.get(&(day, name.to_string())).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Parses every value, checking that it is for a parameter of one of
+    /// `days`, of the parameter's type and within its bounds.
+    pub fn values(&self, days: &[Entry]) -> Result<Values, String> {
+        let mut values = Values::default();
+        for ((day, name), value) in self.0.iter() {
+            let entry = days
+                .iter()
+                .find(|entry| entry.day == *day)
+                .ok_or_else(|| format!("day {} is not solved", day))?;
+            let param = entry
+                .params
+                .iter()
+                .find(|param| param.name() == name)
+                .ok_or_else(|| match entry.params {
+                    [] => format!("day {} has no parameters", day),
+                    params => {
+                        let names: Vec<_> = params.iter().map(|param| param.name()).collect();
+                        format!(
+                            "day {} has no parameter {}, expected one of: {}",
+                            day,
+                            name,
+                            names.join(", ")
+                        )
+                    }
+                })?;
+            let parsed = param.parse(value).ok_or_else(|| {
+                let expected = match param.bounds() {
+                    Some(bounds) => format!("{} {}", param.kind(), bounds),
+                    None => param.kind().to_string(),
+                };
+                format!(
+                    "invalid value for day {} {}: {} (expected {})",
+                    day, name, value, expected
+                )
+            })?;
+            values.0.insert((*day, param.name()), parsed);
+        }
+        Ok(values)
+    }
+}
+
+/// Overridden values of parameters, parsed from [`Overrides`], by day and name.
+#[derive(Clone, Default)]
+pub struct Values(BTreeMap<(u32, &'static str), Arc<dyn Any + Send + Sync>>);
+
+impl Values {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+thread_local! {
+    /// The values overridden on this thread.
+    static VALUES: RefCell<Values> = RefCell::new(Values::default());
+}
+
+/// Calls `f` with the parameters it reads overridden by `values`.
+pub fn scope<R>(values: &Values, f: impl FnOnce() -> R) -> R {
+    let outer = VALUES.with(|current| current.replace(values.clone()));
+    let result = f();
+    VALUES.with(|current| current.replace(outer));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseError;
+    use crate::Solution;
+
+    const SIZE: Param<usize> = Param::new(30, "size", 3, "Size of the thing")
+        .at_least(1)
+        .at_most(10);
+
+    /// A day with a parameter, out of the range of the real ones.
+    struct Day30;
+
+    impl Solution for Day30 {
+        const DAY: u32 = 30;
+        const PARAMS: &'static [&'static dyn Parameter] = &[&SIZE];
+        type Input = ();
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+        fn part1(_input: &Self::Input) -> Option<Self::Part1> {
+            Some(SIZE.get())
+        }
+        fn part2(_input: &Self::Input) -> Option<Self::Part2> {
+            None
+        }
+    }
+
+    #[test]
+    fn scoped_overrides() {
+        let mut overrides = Overrides::default();
+        overrides.set_arg(30, "size=5").unwrap();
+        let values = overrides.values(&[Entry::new::<Day30>()]).unwrap();
+        assert_eq!(scope(&values, || SIZE.get()), 5);
+        assert_eq!(SIZE.get(), 3);
+        assert!(overrides.set_arg(30, "size").is_err());
+    }
+
+    #[test]
+    fn parse_sections() {
+        let overrides =
+            Overrides::parse("# knobs\n\n[day30]\nsize = 7 # bigger\n[day2]\nx=1").unwrap();
+        assert_eq!(overrides.get(30, "size"), Some("7"));
+        assert_eq!(overrides.get(2, "x"), Some("1"));
+        assert_eq!(
+            Overrides::parse("size = 7"),
+            Err(String::from("line 1: expected `[dayN]` first"))
+        );
+        assert_eq!(
+            Overrides::parse("[day30]\nsize"),
+            Err(String::from("line 2: expected `name = value`: size"))
+        );
+        assert!(Overrides::parse("[week1]").is_err());
+    }
+
+    #[test]
+    fn checked_against_days() {
+        let days = [Entry::new::<Day30>()];
+        let check = |text: &str| {
+            let values = Overrides::parse(text).unwrap().values(&days)?;
+            Ok(scope(&values, || SIZE.get()))
+        };
+        assert_eq!(check("[day30]\nsize = 4"), Ok(4));
+        assert_eq!(check(""), Ok(3));
+        assert_eq!(
+            check("[day30]\nsize = -4"),
+            Err(String::from(
+                "invalid value for day 30 size: -4 (expected usize from 1 to 10)"
+            ))
+        );
+        assert_eq!(
+            check("[day30]\nsize = 0"),
+            Err(String::from(
+                "invalid value for day 30 size: 0 (expected usize from 1 to 10)"
+            ))
+        );
+        assert_eq!(
+            check("[day30]\nsize = 11"),
+            Err(String::from(
+                "invalid value for day 30 size: 11 (expected usize from 1 to 10)"
+            ))
+        );
+        assert_eq!(
+            check("[day30]\nlength = 4"),
+            Err(String::from(
+                "day 30 has no parameter length, expected one of: size"
+            ))
+        );
+        assert_eq!(
+            check("[day31]\nsize = 4"),
+            Err(String::from("day 31 is not solved"))
+        );
+    }
+}
@@ -0,0 +1,409 @@
+//! Line-oriented parsing of puzzle inputs.
+//!
+//! Most inputs are lists of lines following a fixed shape, which a [`Template`]
+//! describes by writing `{}` where each value goes:
+//!
+//! ```
+//! use aoc2018::parse::{Cursor, Template};
+//!
+//! let claim = Template::new("#{} @ {},{}: {}x{}");
+//! let (id, x, y, width, height): (u32, u16, u16, u8, u8) =
+//!     claim.parse(&mut Cursor::new("#3 @ 5,5: 2x2")).unwrap();
+//! assert_eq!((id, x, y, width, height), (3, 5, 5, 2, 2));
+//! ```
+//!
+//! Values are read by their [`Field`] implementation, which is provided for
+//! every integer type and can be implemented by days for their own tokens.
+//! Whitespace in a template matches any amount of whitespace, including none,
+//! and numbers may be preceded by whitespace.
+//!
+//! Parsing stops at the first error, unless run within [`all_errors`], where
+//! [`lines`] goes on with the next line to find every bad one.
+
+use std::cell::RefCell;
+use std::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+use crate::error::{LineError, ParseError};
+
+thread_local! {
+    /// The errors found so far within [`all_errors`], `None` outside of it.
+    static FOUND: RefCell<Option<Vec<ParseError>>> = const { RefCell::new(None) };
+}
+
+/// Whether parsers should go on after an error, reporting it with [`report`].
+pub fn collecting() -> bool {
+    FOUND.with(|found| found.borrow().is_some())
+}
+
+/// Records `error` for [`all_errors`], and returns it.
+pub fn report(error: ParseError) -> ParseError {
+    FOUND.with(|found| {
+        if let Some(found) = found.borrow_mut().as_mut() {
+            found.push(error.clone());
+        }
+    });
+    error
+}
+
+/// Every error found by `parse`, in input order, going on after bad lines.
+pub fn all_errors<T>(parse: impl FnOnce() -> Result<T, ParseError>) -> Vec<ParseError> {
+    let outer = FOUND.with(|found| found.replace(Some(Vec::new())));
+    let result = parse();
+    let mut errors = FOUND.with(|found| found.replace(outer)).unwrap_or_default();
+    if let Err(e) = result {
+        if !errors.contains(&e) {
+            errors.push(e);
+        }
+    }
+    errors.sort_by_key(|e| (e.line, e.column));
+    errors
+}
+
+/// Reads tokens from a single line, tracking the column for error reporting.
+pub struct Cursor<'a> {
+    line: &'a str,
+    /// Byte offset of the next character.
+    position: usize,
+    /// 1-based column of the next character, kept alongside `position`.
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Cursor {
+            line,
+            position: 0,
+            column: 1,
+        }
+    }
+
+    /// 1-based column of the next character.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        self.column += 1;
+        Some(c)
+    }
+
+    /// The token starting at the cursor, as shown in error messages.
+    pub fn found(&self) -> String {
+        let rest = self.rest();
+        match rest.chars().next() {
+            None => String::from("end of line"),
+            Some(c) if c.is_alphanumeric() => {
+                rest.chars().take_while(|c| c.is_alphanumeric()).collect()
+            }
+            Some(c) => format!("`{}`", c),
+        }
+    }
+
+    /// An error at the cursor, reporting the token found there.
+    pub fn error(&self, expected: impl Into<String>) -> LineError {
+        LineError::new(self.column(), expected, self.found())
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Consumes one character accepted by `accept`, which names it in errors.
+    pub fn char(
+        &mut self,
+        expected: &str,
+        accept: impl Fn(char) -> bool,
+    ) -> Result<char, LineError> {
+        match self.peek() {
+            Some(c) if accept(c) => {
+                self.bump();
+                Ok(c)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    /// Parses a [`Field`] at the cursor.
+    pub fn parse<T: Field>(&mut self) -> Result<T, LineError> {
+        T::parse(self)
+    }
+
+    pub fn end(&self) -> Result<(), LineError> {
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+/// A value that can be read from a line.
+pub trait Field: Sized {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError>;
+}
+
+/// Reads an integer literal, skipping leading whitespace, and converts it with `FromStr`.
+fn integer<T: std::str::FromStr>(
+    cursor: &mut Cursor,
+    signed: bool,
+    expected: &str,
+    name: &str,
+) -> Result<T, LineError> {
+    cursor.skip_whitespace();
+    let start = cursor.position;
+    let column = cursor.column();
+    if signed && matches!(cursor.peek(), Some('-') | Some('+')) {
+        cursor.bump();
+    }
+    while cursor.peek().is_some_and(|c| c.is_ascii_digit()) {
+        cursor.bump();
+    }
+    let digits = &cursor.line[start..cursor.position];
+    if !digits.chars().any(|c| c.is_ascii_digit()) {
+        cursor.position = start;
+        cursor.column = column;
+        return Err(cursor.error(expected));
+    }
+    digits.parse().map_err(|_| {
+        if digits
+            .trim_start_matches(['-', '+'])
+            .bytes()
+            .all(|b| b == b'0')
+        {
+            // Only zero can fail to parse without overflowing, for the `NonZero` types
+            LineError::new(column, expected, digits)
+        } else {
+            LineError::new(
+                column,
+                format!("{} that fits in {}", expected, name),
+                digits,
+            )
+        }
+    })
+}
+
+macro_rules! integer_fields {
+    ( $signed:expr, $expected:expr; $( $t:ty ),* ) => {
+        $(
+            impl Field for $t {
+                fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+                    integer(cursor, $signed, $expected, stringify!($t))
+                }
+            }
+        )*
+    };
+}
+
+integer_fields!(false, "unsigned integer"; u8, u16, u32, u64, u128, usize);
+integer_fields!(true, "integer"; i8, i16, i32, i64, i128, isize);
+integer_fields!(
+    false,
+    "positive integer";
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize
+);
+
+/// A single non-whitespace character.
+impl Field for char {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        cursor.char("character", |c| !c.is_whitespace())
+    }
+}
+
+/// A run of non-whitespace characters.
+impl Field for String {
+    fn parse(cursor: &mut Cursor) -> Result<Self, LineError> {
+        let word: String = cursor
+            .rest()
+            .chars()
+            .take_while(|c| !c.is_whitespace())
+            .collect();
+        if word.is_empty() {
+            return Err(cursor.error("word"));
+        }
+        cursor.position += word.len();
+        cursor.column += word.chars().count();
+        Ok(word)
+    }
+}
+
+/// The shape of a line, with `{}` standing for each value.
+pub struct Template<'t> {
+    literals: Vec<&'t str>,
+}
+
+impl<'t> Template<'t> {
+    pub fn new(pattern: &'t str) -> Self {
+        Template {
+            literals: pattern.split("{}").collect(),
+        }
+    }
+
+    /// Number of values in the template.
+    pub fn holes(&self) -> usize {
+        self.literals.len() - 1
+    }
+
+    /// Matches the literal text before value `index` (or after the last value).
+    fn literal(&self, index: usize, cursor: &mut Cursor) -> Result<(), LineError> {
+        let literal = self.literals[index];
+        let mut chars = literal.char_indices().peekable();
+        while let Some((offset, c)) = chars.next() {
+            if c.is_whitespace() {
+                cursor.skip_whitespace();
+                while chars.peek().is_some_and(|(_, c)| c.is_whitespace()) {
+                    chars.next();
+                }
+            } else if cursor.peek() == Some(c) {
+                cursor.bump();
+            } else {
+                let expected = literal[offset..].trim_end();
+                return Err(cursor.error(format!("`{}`", expected)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Matches the whole line, returning its values.
+    pub fn parse<T: Fields>(&self, cursor: &mut Cursor) -> Result<T, LineError> {
+        let values = self.parse_prefix(cursor)?;
+        cursor.end()?;
+        Ok(values)
+    }
+
+    /// Matches the start of the line, leaving the cursor after the template.
+    pub fn parse_prefix<T: Fields>(&self, cursor: &mut Cursor) -> Result<T, LineError> {
+        assert_eq!(
+            self.holes(),
+            T::COUNT,
+            "template {:?} does not match the number of values",
+            self.literals.join("{}")
+        );
+        T::parse(self, cursor)
+    }
+}
+
+/// The values matched by a [`Template`]: a single [`Field`] or a tuple of them.
+pub trait Fields: Sized {
+    const COUNT: usize;
+    fn parse(template: &Template, cursor: &mut Cursor) -> Result<Self, LineError>;
+}
+
+impl<T: Field> Fields for T {
+    const COUNT: usize = 1;
+    fn parse(template: &Template, cursor: &mut Cursor) -> Result<Self, LineError> {
+        template.literal(0, cursor)?;
+        let value = T::parse(cursor)?;
+        template.literal(1, cursor)?;
+        Ok(value)
+    }
+}
+
+impl Fields for () {
+    const COUNT: usize = 0;
+    fn parse(template: &Template, cursor: &mut Cursor) -> Result<Self, LineError> {
+        template.literal(0, cursor)
+    }
+}
+
+macro_rules! tuple_fields {
+    ( $count:expr; $( $t:ident $index:expr ),* ) => {
+        impl<$( $t: Field ),*> Fields for ($( $t, )*) {
+            const COUNT: usize = $count;
+            fn parse(template: &Template, cursor: &mut Cursor) -> Result<Self, LineError> {
+                let values = ($(
+                    {
+                        template.literal($index, cursor)?;
+                        $t::parse(cursor)?
+                    },
+                )*);
+                template.literal($count, cursor)?;
+                Ok(values)
+            }
+        }
+    };
+}
+
+tuple_fields!(2; A 0, B 1);
+tuple_fields!(3; A 0, B 1, C 2);
+tuple_fields!(4; A 0, B 1, C 2, D 3);
+tuple_fields!(5; A 0, B 1, C 2, D 3, E 4);
+tuple_fields!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Parses every line of day `day`'s input with `parse_line`.
+pub fn lines<T>(
+    day: u32,
+    input: &str,
+    parse_line: impl Fn(&mut Cursor) -> Result<T, LineError>,
+) -> Result<Vec<T>, ParseError> {
+    numbered_lines(day, input.lines().enumerate(), parse_line)
+}
+
+/// Parses lines paired with their 0-based index in day `day`'s input with
+/// `parse_line`, for inputs whose lines are not all alike.
+pub fn numbered_lines<'a, T>(
+    day: u32,
+    lines: impl IntoIterator<Item = (usize, &'a str)>,
+    parse_line: impl Fn(&mut Cursor) -> Result<T, LineError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut values = Vec::new();
+    let mut first = None;
+    for (index, line) in lines {
+        match parse_line(&mut Cursor::new(line)) {
+            Ok(value) => values.push(value),
+            Err(e) => {
+                let e = report(e.at(day, index, line));
+                if !collecting() {
+                    return Err(e);
+                }
+                first.get_or_insert(e);
+            }
+        }
+    }
+    first.map_or(Ok(values), Err)
+}
+
+/// Parses an input made of a single line with `parse_line`.
+pub fn single_line<T>(
+    day: u32,
+    input: &str,
+    parse_line: impl Fn(&mut Cursor) -> Result<T, LineError>,
+) -> Result<T, ParseError> {
+    let mut values = lines(day, input, parse_line)?.into_iter();
+    let value = values
+        .next()
+        .ok_or_else(|| end_of_input(day, input, "puzzle input"))?;
+    if values.next().is_some() {
+        let line = input.lines().nth(1).unwrap();
+        return Err(LineError::new(1, "end of input", Cursor::new(line).found()).at(day, 1, line));
+    }
+    Ok(value)
+}
+
+/// An error for input that ends before `expected` is found.
+pub fn end_of_input(day: u32, input: &str, expected: &str) -> ParseError {
+    let text = input.lines().last().unwrap_or("");
+    ParseError {
+        day,
+        line: input.lines().count().max(1),
+        column: text.chars().count() + 1,
+        expected: expected.to_string(),
+        found: String::from("end of input"),
+        text: text.to_string(),
+    }
+}
@@ -0,0 +1,360 @@
+//! Points and vectors in 2, 3 and 4 dimensions, with `y` growing downwards in 2D
+//! as in the puzzle pictures.
+//!
+//! ```
+//! use aoc2018::point::{Bounds, Direction, Point};
+//!
+//! let a = Point::new(1, 6);
+//! let b = Point::new(8, 3);
+//! assert_eq!(a + b, Point::new(9, 9));
+//! assert_eq!((a.manhattan(b), a.chebyshev(b)), (10, 7));
+//! assert_eq!(Bounds::around([a, b]).unwrap().extent(), Point::new(7, 3));
+//! assert_eq!(a.step(Direction::Up.turn_right()), Point::new(2, 6));
+//! ```
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Integer types that points are made of.
+pub trait Coordinate:
+    Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ONE: Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// Distance between two values, without overflowing for unsigned types.
+    fn distance(self, other: Self) -> Self {
+        self.max(other) - self.min(other)
+    }
+}
+
+macro_rules! coordinates {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl Coordinate for $t {
+                const ONE: Self = 1;
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+coordinates!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Defines a point type with its operators, distances and bounding boxes.
+macro_rules! point {
+    ( $(#[$doc:meta])* $name:ident { $( $field:ident ),+ } ) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name<T> {
+            $( pub $field: T, )+
+        }
+
+        impl<T> $name<T> {
+            pub const fn new($( $field: T ),+) -> Self {
+                $name { $( $field ),+ }
+            }
+        }
+
+        impl<T: Coordinate> $name<T> {
+            /// Sum of the distances along each axis.
+            pub fn manhattan(self, other: Self) -> T {
+                let mut sum = T::default();
+                $( sum = sum + self.$field.distance(other.$field); )+
+                sum
+            }
+
+            /// Largest of the distances along each axis.
+            pub fn chebyshev(self, other: Self) -> T {
+                let mut max = T::default();
+                $( max = max.max(self.$field.distance(other.$field)); )+
+                max
+            }
+
+            /// The smallest value of each coordinate.
+            pub fn min(self, other: Self) -> Self {
+                $name { $( $field: self.$field.min(other.$field) ),+ }
+            }
+
+            /// The largest value of each coordinate.
+            pub fn max(self, other: Self) -> Self {
+                $name { $( $field: self.$field.max(other.$field) ),+ }
+            }
+
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                Some($name { $( $field: self.$field.checked_add(other.$field)? ),+ })
+            }
+
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                Some($name { $( $field: self.$field.checked_sub(other.$field)? ),+ })
+            }
+        }
+
+        impl<T: Coordinate> Componentwise for $name<T> {
+            fn min(self, other: Self) -> Self {
+                $name::min(self, other)
+            }
+            fn max(self, other: Self) -> Self {
+                $name::max(self, other)
+            }
+            fn le(self, other: Self) -> bool {
+                $( self.$field <= other.$field )&&+
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                $name { $( $field: self.$field + other.$field ),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                $name { $( $field: self.$field - other.$field ),+ }
+            }
+        }
+
+        /// Scales every coordinate.
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $name<T> {
+            type Output = Self;
+            fn mul(self, factor: T) -> Self {
+                $name { $( $field: self.$field * factor ),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $name<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                $name { $( $field: -self.$field ),+ }
+            }
+        }
+
+        impl<T: Copy + Add<Output = T>> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Copy + Sub<Output = T>> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> MulAssign<T> for $name<T> {
+            fn mul_assign(&mut self, factor: T) {
+                *self = *self * factor;
+            }
+        }
+    };
+}
+
+point!(
+    /// A point in 2D, or the vector between two of them.
+    Point { x, y }
+);
+point!(
+    /// A point in 3D.
+    Point3 { x, y, z }
+);
+point!(
+    /// A point in 4D.
+    Point4 { x, y, z, w }
+);
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    /// The next point in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Point::new(self.x, self.y - T::ONE),
+            Direction::Down => Point::new(self.x, self.y + T::ONE),
+            Direction::Left => Point::new(self.x - T::ONE, self.y),
+            Direction::Right => Point::new(self.x + T::ONE, self.y),
+        }
+    }
+
+    /// The next point in `direction`, `None` if it does not fit in `T`.
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        Some(match direction {
+            Direction::Up => Point::new(self.x, self.y.checked_sub(T::ONE)?),
+            Direction::Down => Point::new(self.x, self.y.checked_add(T::ONE)?),
+            Direction::Left => Point::new(self.x.checked_sub(T::ONE)?, self.y),
+            Direction::Right => Point::new(self.x.checked_add(T::ONE)?, self.y),
+        })
+    }
+}
+
+/// Points compared coordinate by coordinate, for [`Bounds`].
+pub trait Componentwise: Copy + Sub<Output = Self> {
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    /// Whether every coordinate is at most the other's.
+    fn le(self, other: Self) -> bool;
+}
+
+/// The box between two corners, both included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+impl<P: Componentwise> Bounds<P> {
+    /// The smallest box holding every point, `None` if there are none.
+    pub fn around(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |bounds, point| Bounds {
+                min: bounds.min.min(point),
+                max: bounds.max.max(point),
+            },
+        ))
+    }
+
+    pub fn contains(&self, point: P) -> bool {
+        self.min.le(point) && point.le(self.max)
+    }
+
+    /// Distance from the smallest corner to the largest along each axis.
+    pub fn extent(&self) -> P {
+        self.max - self.min
+    }
+}
+
+/// A direction on the screen, `Up` towards smaller `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// A quarter turn, clockwise when seen on the screen.
+    pub fn turn(self, clockwise: bool) -> Direction {
+        if clockwise {
+            self.turn_right()
+        } else {
+            self.turn_left()
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// `^`, `v`, `<` or `>`.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators() {
+        let mut p = Point::new(3, -2);
+        p += Point::new(1, 1);
+        assert_eq!(p, Point::new(4, -1));
+        p -= Point::new(4, 4);
+        assert_eq!(p, Point::new(0, -5));
+        assert_eq!(-p * 2, Point::new(0, 10));
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn distances_do_not_overflow_unsigned() {
+        let a = Point::new(5usize, 1);
+        let b = Point::new(1usize, 4);
+        assert_eq!((a.manhattan(b), b.manhattan(a)), (7, 7));
+        assert_eq!(a.chebyshev(b), 4);
+        let a = Point4::new(0, 3, 0, -1);
+        assert_eq!(
+            (
+                a.manhattan(Point4::default()),
+                a.chebyshev(Point4::default())
+            ),
+            (4, 3)
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let points = [Point3::new(1, 5, -1), Point3::new(-2, 0, 3)];
+        let bounds = Bounds::around(points).unwrap();
+        assert_eq!(bounds.min, Point3::new(-2, 0, -1));
+        assert_eq!(bounds.max, Point3::new(1, 5, 3));
+        assert!(bounds.contains(Point3::new(0, 0, 0)));
+        assert!(!bounds.contains(Point3::new(0, 6, 0)));
+        assert_eq!(Bounds::<Point<i32>>::around([]), None);
+    }
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            let p = Point::new(1usize, 1);
+            assert_eq!(p.step(direction).step(direction.reverse()), p);
+        }
+        assert_eq!(Point::new(0usize, 3).checked_step(Direction::Left), None);
+    }
+}
@@ -0,0 +1,79 @@
+//! Runs independent jobs on a fixed number of scoped threads.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Calls `f` on every item using up to `jobs` threads, and hands the results to
+/// `each` in the order of the items, as soon as all the earlier ones are done.
+pub fn ordered<T, R, F>(jobs: usize, items: &[T], f: F, mut each: impl FnMut(R))
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                if sender.send((index, f(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+        // Hold back results finished ahead of an earlier item
+        let mut pending = BTreeMap::new();
+        let mut done = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&done) {
+                each(result);
+                done += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn results_come_in_item_order() {
+        let items: Vec<u64> = (0..20).collect();
+        for jobs in [1, 3, 50] {
+            let mut results = Vec::new();
+            ordered(
+                jobs,
+                &items,
+                |&item| {
+                    // Later items finish first
+                    thread::sleep(Duration::from_millis(20 - item));
+                    item * 2
+                },
+                |result| results.push(result),
+            );
+            assert_eq!(
+                results,
+                items.iter().map(|item| item * 2).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn no_items() {
+        let mut called = false;
+        ordered(4, &[] as &[u32], |&item| item, |_| called = true);
+        assert!(!called);
+    }
+}
@@ -0,0 +1,188 @@
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+use crate::json::Value;
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub part: Part,
+    /// `None` if the input has no answer for this part.
+    pub answer: Option<String>,
+    /// Named intermediate values behind the answer.
+    pub values: Vec<(&'static str, Value)>,
+    /// How the answer was derived, one step per line.
+    pub explanation: Vec<String>,
+    pub time: Duration,
+}
+
+pub struct DayResult {
+    pub day: u32,
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    /// Time spent parsing and solving every part.
+    pub fn time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+
+    /// The result as a JSON object, with times in nanoseconds, and with the
+    /// explanation of each answer if `explain`.
+    pub fn to_json(&self, explain: bool) -> Value {
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| {
+                let mut fields = vec![
+                    ("part", Value::Number(part.part.to_string())),
+                    ("answer", Value::from(part.answer.clone())),
+                    ("time_ns", Value::from(part.time.as_nanos())),
+                    ("values", Value::object(part.values.clone())),
+                ];
+                if explain {
+                    fields.push(("explanation", Value::from(part.explanation.clone())));
+                }
+                Value::object(fields)
+            })
+            .collect();
+        Value::object(vec![
+            ("day", Value::from(self.day)),
+            ("time_ns", Value::from(self.time().as_nanos())),
+            ("parse_time_ns", Value::from(self.parse_time.as_nanos())),
+            ("parts", Value::Array(parts)),
+        ])
+    }
+}
+
+/// Type-erased entry point for a [`Solution`], so days can be selected at runtime.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u32,
+    /// The parameters of the puzzle, see [`Solution::PARAMS`].
+    pub params: &'static [&'static dyn Parameter],
+    run: fn(&str, &[Part]) -> Result<DayResult, ParseError>,
+    parse: fn(&str) -> Result<(), ParseError>,
+    check: fn(&str) -> Vec<ParseError>,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>() -> Self {
+        Entry {
+            day: S::DAY,
+            params: S::PARAMS,
+            run: run::<S>,
+            parse: |input| S::parse(input).map(|_| ()),
+            check: |input| parse::all_errors(|| S::parse(input)),
+        }
+    }
+
+    /// Checks that `input` parses, without solving it.
+    pub fn parse(&self, input: &str) -> Result<(), ParseError> {
+        (self.parse)(input)
+    }
+
+    /// Every error in `input`, going on after bad lines where the day's
+    /// grammar allows it.
+    pub fn check(&self, input: &str) -> Vec<ParseError> {
+        (self.check)(input)
+    }
+
+    /// Parses `input` once and solves each of `parts` on it.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<DayResult, ParseError> {
+        (self.run)(input, parts)
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<DayResult, ParseError> {
+    let start = Instant::now();
+    let parsed = trace::scope(S::DAY, None, || S::parse(input))?;
+    let parse_time = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = trace::scope(S::DAY, Some(part), || match part {
+                Part::One => S::part1(&parsed).map(|answer| describe(&answer)),
+                Part::Two => S::part2(&parsed).map(|answer| describe(&answer)),
+            });
+            let time = start.elapsed();
+            let (answer, values, explanation) = match answer {
+                Some((answer, values, explanation)) => (Some(answer), values, explanation),
+                None => (None, Vec::new(), Vec::new()),
+            };
+            PartResult {
+                part,
+                answer,
+                values,
+                explanation,
+                time,
+            }
+        })
+        .collect();
+    Ok(DayResult {
+        day: S::DAY,
+        parse_time,
+        parts,
+    })
+}
+
+/// The answer, its named values and its explanation.
+fn describe(answer: &impl Answer) -> (String, Vec<(&'static str, Value)>, Vec<String>) {
+    (answer.to_string(), answer.values(), answer.explain())
+}
+
+/// The solved days, except those left out by the cargo features.
+pub const DAYS: &[Entry] = &[
+    #[cfg(feature = "week1")]
+    Entry::new::<day1::Day1>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day2::Day2>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day3::Day3>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day4::Day4>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day5::Day5>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day6::Day6>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day7::Day7>(),
+    #[cfg(feature = "week2")]
+    Entry::new::<day8::Day8>(),
+    #[cfg(all(feature = "week2", feature = "heavy"))]
+    Entry::new::<day9::Day9>(),
+    #[cfg(feature = "week2")]
+    Entry::new::<day10::Day10>(),
+    #[cfg(feature = "week2")]
+    Entry::new::<day11::Day11>(),
+    #[cfg(feature = "week2")]
+    Entry::new::<day12::Day12>(),
+    #[cfg(feature = "week2")]
+    Entry::new::<day13::Day13>(),
+    #[cfg(all(feature = "week2", feature = "heavy"))]
+    Entry::new::<day14::Day14>(),
+];
+
+pub fn find(day: u32) -> Option<&'static Entry> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
@@ -0,0 +1,278 @@
+//! Scaffolding for `aoc new`: a new day's module, registered with the library
+//! and the runner, and an empty input file to paste the puzzle input into.
+//!
+//! The day is built with the cargo feature of its week, see [`feature`].
+//!
+//! Answers are left out of `answers.txt` until they are recorded.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The cargo feature building day `day`: `week1` for days 1 to 7, `week2`
+/// for days 8 to 14 and so on.
+pub fn feature(day: u32) -> String {
+    format!("week{}", (day - 1) / 7 + 1)
+}
+
+/// The source of a new day's module: a [`Solution`](crate::Solution) parsing
+/// the input as lines and solving neither part, with tests for the example
+/// from the puzzle to fill in.
+pub fn day_module(day: u32) -> String {
+    format!(
+        r#"#![cfg(feature = "{feature}")]
+
+use crate::error::ParseError;
+use crate::parse::{{self, Template}};
+use crate::Solution;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u32 = {day};
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        let line = Template::new("{{}}");
+        parse::lines(Self::DAY, input, |cursor| line.parse(cursor))
+    }}
+
+    fn part1(_input: &Self::Input) -> Option<Self::Part1> {{
+        None
+    }}
+
+    fn part2(_input: &Self::Input) -> Option<Self::Part2> {{
+        None
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    // The example from the puzzle description
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part1_example() {{
+        assert_eq!(Day{day}::part1(&Day{day}::parse(EXAMPLE).unwrap()), None);
+    }}
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part2_example() {{
+        assert_eq!(Day{day}::part2(&Day{day}::parse(EXAMPLE).unwrap()), None);
+    }}
+}}
+"#,
+        day = day,
+        feature = feature(day)
+    )
+}
+
+/// The day number of a `pub mod dayN;` line.
+fn module_day(line: &str) -> Option<u32> {
+    line.strip_prefix("pub mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+/// The day number of an `Entry::new::<dayN::DayN>(),` line.
+fn entry_day(line: &str) -> Option<u32> {
+    line.trim()
+        .strip_prefix("Entry::new::<day")?
+        .split_once("::")?
+        .0
+        .parse()
+        .ok()
+}
+
+/// Inserts `line` into `text` before the first line `after` returns false for
+/// and its attributes, among the lines `day_of` gives a day for, keeping them
+/// ordered.
+fn insert_line(
+    text: &str,
+    line: String,
+    day_of: impl Fn(&str) -> Option<u32>,
+    after: impl Fn(u32) -> bool,
+) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let days: Vec<usize> = (0..lines.len())
+        .filter(|&index| day_of(lines[index]).is_some())
+        .collect();
+    let index = days
+        .iter()
+        .find(|&&index| !after(day_of(lines[index]).unwrap()))
+        .map_or(days.last()? + 1, |&index| {
+            let attributes = lines[..index]
+                .iter()
+                .rev()
+                .take_while(|line| line.trim_start().starts_with("#["))
+                .count();
+            index - attributes
+        });
+    let mut inserted: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    inserted.insert(index, line);
+    Some(inserted.join("\n") + "\n")
+}
+
+/// `lib_rs` with `pub mod dayN;` added among the other days, sorted by name
+/// as rustfmt does.
+pub fn register_module(lib_rs: &str, day: u32) -> Result<String, String> {
+    if lib_rs.lines().any(|line| module_day(line) == Some(day)) {
+        return Err(format!("day{} is already declared in lib.rs", day));
+    }
+    let name = format!("day{}", day);
+    insert_line(lib_rs, format!("pub mod {};", name), module_day, |other| {
+        format!("day{}", other) < name
+    })
+    .ok_or_else(|| String::from("no day modules found in lib.rs"))
+}
+
+/// `runner_rs` with the day's entry added to `DAYS`, in day order, built with
+/// the day's feature.
+pub fn register_entry(runner_rs: &str, day: u32) -> Result<String, String> {
+    if runner_rs.lines().any(|line| entry_day(line) == Some(day)) {
+        return Err(format!("day{} is already in the runner", day));
+    }
+    let lines: Vec<&str> = runner_rs.lines().collect();
+    let indent = lines
+        .iter()
+        .find(|line| entry_day(line).is_some())
+        .map_or("", |line| &line[..line.len() - line.trim_start().len()]);
+    let entry = format!(
+        "{0}#[cfg(feature = \"{1}\")]\n{0}Entry::new::<day{2}::Day{2}>(),",
+        indent,
+        feature(day),
+        day
+    );
+    insert_line(runner_rs, entry, entry_day, |other| other < day)
+        .ok_or_else(|| String::from("no days found in the runner"))
+}
+
+/// Creates day `day` in the crate at `root`, with its empty input at
+/// `input_path`. Returns the files created or changed, in order; nothing is
+/// written if the day already exists.
+pub fn create(root: &Path, input_path: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day: {}", day));
+    }
+    let module = root.join("src").join(format!("day{}.rs", day));
+    for path in [&module, input_path] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e));
+    let lib = root.join("src").join("lib.rs");
+    let runner = root.join("src").join("runner.rs");
+    let lib_rs = register_module(&read(&lib)?, day)?;
+    let runner_rs = register_entry(&read(&runner)?, day)?;
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+    };
+    write(&module, &day_module(day))?;
+    write(&lib, &lib_rs)?;
+    write(&runner, &runner_rs)?;
+    if let Some(dir) = input_path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    write(input_path, "")?;
+    Ok(vec![module, lib, runner, input_path.to_path_buf()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str =
+        "pub mod answers;\n\npub mod day1;\npub mod day10;\npub mod day2;\n\npub use x;\n";
+    const RUNNER: &str = r#"pub const DAYS: &[Entry] = &[
+    #[cfg(feature = "week1")]
+    Entry::new::<day1::Day1>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day2::Day2>(),
+    #[cfg(feature = "week2")]
+    Entry::new::<day10::Day10>(),
+];
+"#;
+
+    #[test]
+    fn modules_sorted_by_name() {
+        assert_eq!(
+            register_module(LIB, 15).unwrap(),
+            "pub mod answers;\n\npub mod day1;\npub mod day10;\npub mod day15;\npub mod day2;\n\npub use x;\n"
+        );
+        assert_eq!(
+            register_module(LIB, 3).unwrap(),
+            "pub mod answers;\n\npub mod day1;\npub mod day10;\npub mod day2;\npub mod day3;\n\npub use x;\n"
+        );
+        assert!(register_module(LIB, 10).is_err());
+    }
+
+    #[test]
+    fn entries_in_day_order() {
+        assert_eq!(
+            register_entry(RUNNER, 3).unwrap(),
+            r#"pub const DAYS: &[Entry] = &[
+    #[cfg(feature = "week1")]
+    Entry::new::<day1::Day1>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day2::Day2>(),
+    #[cfg(feature = "week1")]
+    Entry::new::<day3::Day3>(),
+    #[cfg(feature = "week2")]
+    Entry::new::<day10::Day10>(),
+];
+"#
+        );
+        assert!(register_entry(RUNNER, 15).unwrap().ends_with(
+            "Day10>(),\n    #[cfg(feature = \"week3\")]\n    Entry::new::<day15::Day15>(),\n];\n"
+        ));
+        assert!(register_entry(RUNNER, 2).is_err());
+    }
+
+    #[test]
+    fn module_names_the_day() {
+        let module = day_module(15);
+        assert!(module.contains("pub struct Day15;"));
+        assert!(module.contains("const DAY: u32 = 15;"));
+        assert!(module.starts_with("#![cfg(feature = \"week3\")]\n"));
+        assert!(module.contains("Template::new(\"{}\")"));
+    }
+
+    #[test]
+    fn refuses_existing_days() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let input = root.join("data").join("day7.txt");
+        assert!(create(root, &input, 7)
+            .unwrap_err()
+            .ends_with("day7.rs already exists"));
+        assert!(create(root, &input, 26).is_err());
+    }
+
+    #[test]
+    fn creates_a_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/runner.rs"), RUNNER).unwrap();
+        let input = root.join("data").join("day15.txt");
+        let created = create(&root, &input, 15).unwrap();
+        assert_eq!(created[0], root.join("src/day15.rs"));
+        assert_eq!(fs::read_to_string(&input).unwrap(), "");
+        assert!(fs::read_to_string(root.join("src/lib.rs"))
+            .unwrap()
+            .contains("pub mod day15;"));
+        assert!(!root.join("data/answers.txt").exists());
+        assert!(create(&root, &input, 15)
+            .unwrap_err()
+            .ends_with("day15.rs already exists"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
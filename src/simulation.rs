@@ -0,0 +1,286 @@
+//! Simulations that eventually repeat, fast-forwarded to far away generations.
+//!
+//! States are compared by key after being normalized, so that a state that
+//! only moves, such as day 12's plants drifting to the right, counts as
+//! repeating. The runner keeps track of how far normalizing moved the state.
+//!
+//! Repeats are found with Brent's algorithm. Each generation is traced once:
+//! replaying generations while looking for the start of the cycle emits no
+//! events.
+
+use crate::trace;
+
+/// A state advanced one generation at a time.
+pub trait Simulation {
+    type Key: PartialEq;
+
+    fn step(&mut self);
+
+    /// Identifies the normalized state: equal keys must mean equal futures.
+    fn state_key(&self) -> Self::Key;
+
+    /// Moves the state to its canonical position, returning how far it moved.
+    /// Simulations whose states only repeat in place can keep the default.
+    fn normalize(&mut self) -> i64 {
+        0
+    }
+}
+
+/// How the states of a simulation repeat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// From generation `at` on, each step leaves the state unchanged but
+    /// moved by `shift`.
+    Converged { at: usize, shift: i64 },
+    /// From generation `start` on, the states repeat every `period`
+    /// generations, moved by `shift` each time.
+    Cycled {
+        start: usize,
+        period: usize,
+        shift: i64,
+    },
+    /// No state repeated within the limit.
+    Diverged,
+}
+
+/// A state with the distance normalizing it moved it, since the first generation.
+struct Tracked<S> {
+    state: S,
+    translation: i64,
+}
+
+impl<S: Simulation + Clone> Tracked<S> {
+    fn new(initial: &S) -> Self {
+        let mut state = initial.clone();
+        let translation = state.normalize();
+        Tracked { state, translation }
+    }
+
+    fn advance(&mut self) {
+        self.state.step();
+        self.translation += self.state.normalize();
+    }
+}
+
+impl<S: Clone> Clone for Tracked<S> {
+    fn clone(&self) -> Self {
+        Tracked {
+            state: self.state.clone(),
+            translation: self.translation,
+        }
+    }
+}
+
+/// The normalized state after some generations, with how far it moved.
+pub struct Run<S> {
+    pub outcome: Outcome,
+    pub state: S,
+    pub translation: i64,
+}
+
+/// What the first pass of Brent's algorithm found.
+struct Lap<S> {
+    /// Generations between repeated states, or `None` if none repeated
+    /// within the limit.
+    period: Option<usize>,
+    /// The state after the generations asked for, if the hare passed it.
+    target: Option<Tracked<S>>,
+}
+
+/// Finds the period with the first pass of Brent's algorithm, simulating at
+/// most `limit` generations: the hare runs ahead of a tortoise teleported to
+/// it at every power of two. Only the key of the tortoise is kept.
+fn find_period<S: Simulation + Clone>(initial: &S, generations: usize, limit: usize) -> Lap<S> {
+    let mut hare = Tracked::new(initial);
+    let mut target = (generations == 0).then(|| hare.clone());
+    let mut tortoise = hare.state.state_key();
+    let mut power = 1;
+    let mut period = 1;
+    hare.advance();
+    let mut steps = 1;
+    loop {
+        if steps == generations {
+            target = Some(hare.clone());
+        }
+        let key = hare.state.state_key();
+        if key == tortoise {
+            return Lap {
+                period: Some(period),
+                target,
+            };
+        }
+        if steps >= limit {
+            return Lap {
+                period: None,
+                target,
+            };
+        }
+        if power == period {
+            tortoise = key;
+            power *= 2;
+            period = 0;
+        }
+        hare.advance();
+        period += 1;
+        steps += 1;
+    }
+}
+
+/// Finds the start of a cycle of `period` generations with the second pass
+/// of Brent's algorithm: both walk one step at a time, `period` apart.
+/// Returns the outcome along with the first state of the cycle.
+fn find_start<S: Simulation + Clone>(initial: &S, period: usize) -> (Outcome, Tracked<S>) {
+    let mut tortoise = Tracked::new(initial);
+    let mut hare = tortoise.clone();
+    for _ in 0..period {
+        hare.advance();
+    }
+    let mut start = 0;
+    while tortoise.state.state_key() != hare.state.state_key() {
+        tortoise.advance();
+        hare.advance();
+        start += 1;
+    }
+    let shift = hare.translation - tortoise.translation;
+    let outcome = if period == 1 {
+        Outcome::Converged { at: start, shift }
+    } else {
+        Outcome::Cycled {
+            start,
+            period,
+            shift,
+        }
+    };
+    (outcome, tortoise)
+}
+
+/// Simulates `generations` generations, skipping whole cycles once the
+/// states repeat. Returns `None` if no state repeated within `limit`
+/// generations, unless `generations` is within it.
+///
+/// Cycles are found with Brent's algorithm, which keeps a constant number of
+/// states however long it takes the states to repeat.
+pub fn run<S: Simulation + Clone>(initial: &S, generations: usize, limit: usize) -> Option<Run<S>> {
+    let Lap { period, target } = find_period(initial, generations, limit);
+    let Some(period) = period else {
+        let target = target?;
+        return Some(Run {
+            outcome: Outcome::Diverged,
+            state: target.state,
+            translation: target.translation,
+        });
+    };
+    trace::quiet(|| {
+        let (outcome, first) = find_start(initial, period);
+        let target = target.unwrap_or_else(|| {
+            // The hare stopped past the start of the cycle, so the state after
+            // `generations` is as far into the cycle as the state after the
+            // remaining generations past its first state, moved by the whole
+            // cycles skipped
+            let (start, shift) = match outcome {
+                Outcome::Converged { at, shift } => (at, shift),
+                Outcome::Cycled { start, shift, .. } => (start, shift),
+                Outcome::Diverged => unreachable!("a period was found"),
+            };
+            let mut first = first;
+            for _ in 0..(generations - start) % period {
+                first.advance();
+            }
+            first.translation += ((generations - start) / period) as i64 * shift;
+            first
+        });
+        Some(Run {
+            outcome,
+            state: target.state,
+            translation: target.translation,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts modulo `modulus` from `value`, but leaves values below `start` early.
+    #[derive(Clone)]
+    struct Counter {
+        value: u64,
+        start: u64,
+        modulus: u64,
+    }
+
+    impl Simulation for Counter {
+        type Key = u64;
+        fn step(&mut self) {
+            self.value = if self.value < self.start {
+                self.value + 1
+            } else {
+                self.start + (self.value - self.start + 1) % self.modulus
+            };
+        }
+        fn state_key(&self) -> u64 {
+            self.value
+        }
+    }
+
+    /// Moves right by `speed` forever.
+    #[derive(Clone)]
+    struct Walker {
+        position: i64,
+        speed: i64,
+    }
+
+    impl Simulation for Walker {
+        type Key = ();
+        fn step(&mut self) {
+            self.position += self.speed;
+        }
+        fn state_key(&self) {}
+        fn normalize(&mut self) -> i64 {
+            std::mem::take(&mut self.position)
+        }
+    }
+
+    #[test]
+    fn cycles() {
+        let counter = Counter {
+            value: 0,
+            start: 5,
+            modulus: 7,
+        };
+        let run = run(&counter, 1_000_000, 100).unwrap();
+        assert_eq!(
+            run.outcome,
+            Outcome::Cycled {
+                start: 5,
+                period: 7,
+                shift: 0
+            }
+        );
+        assert_eq!(run.state.value, 5 + (1_000_000 - 5) % 7);
+        assert_eq!(super::run(&counter, 3, 100).unwrap().state.value, 3);
+    }
+
+    #[test]
+    fn converges_with_translation() {
+        let walker = Walker {
+            position: 2,
+            speed: 3,
+        };
+        let run = run(&walker, 50_000_000_000, 10).unwrap();
+        assert_eq!(run.outcome, Outcome::Converged { at: 0, shift: 3 });
+        assert_eq!(run.translation, 2 + 3 * 50_000_000_000);
+    }
+
+    #[test]
+    fn diverges() {
+        let counter = Counter {
+            value: 0,
+            start: u64::MAX,
+            modulus: 1,
+        };
+        assert!(run(&counter, 1_000, 100).is_none());
+        let run = run(&counter, 50, 100).unwrap();
+        assert_eq!((run.outcome, run.state.value), (Outcome::Diverged, 50));
+    }
+}
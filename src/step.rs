@@ -0,0 +1,409 @@
+//! Interactive stepping through the simulation-style days.
+//!
+//! A session reads commands line by line:
+//!
+//! - `step [n]` advances `n` steps (default: 1),
+//! - `run-until <name> <op> <value>` steps until a named value compares as
+//!   given, e.g. `run-until carts <= 1`, or `run-until done`,
+//! - `show` draws the current state,
+//! - `back [n]` undoes the last `n` steps (default: 1),
+//! - `help` and `quit`.
+//!
+//! Drawing the states for `show` needs the `visualize` feature; the rest of
+//! a session works in any build.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[cfg(feature = "week1")]
+use crate::day7;
+#[cfg(all(feature = "week2", feature = "heavy"))]
+use crate::day9;
+use crate::error::ParseError;
+#[cfg(any(feature = "week1", feature = "week2"))]
+use crate::Solution;
+#[cfg(feature = "week2")]
+use crate::{day10, day12, day13};
+
+/// Steps remembered for `back`.
+const HISTORY: usize = 1000;
+
+/// Steps taken by `run-until` before giving up.
+const MAX_RUN: usize = 1_000_000;
+
+/// A simulation state that can be advanced and inspected one step at a time.
+pub trait Steppable: Clone {
+    /// Advances one step, or returns `false` if the simulation is over.
+    fn step(&mut self) -> bool;
+    /// Named values describing the state, for status lines and predicates.
+    fn values(&self) -> Vec<(&'static str, i64)>;
+    /// The state drawn for `show`.
+    #[cfg(feature = "visualize")]
+    fn show(&self) -> String;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// A condition such as `height < 10`, or `done` for the end of the simulation.
+#[derive(Debug, PartialEq, Eq)]
+enum Predicate {
+    Done,
+    Compare(String, Op, i64),
+}
+
+impl Predicate {
+    fn parse(text: &str) -> Result<Predicate, String> {
+        let words: Vec<_> = text.split_whitespace().collect();
+        let (name, op, value) = match words[..] {
+            ["done"] => return Ok(Predicate::Done),
+            [name, op, value] => (name, op, value),
+            _ => return Err(String::from("expected `done` or `<name> <op> <value>`")),
+        };
+        let op = match op {
+            "==" => Op::Eq,
+            "!=" => Op::Ne,
+            "<" => Op::Lt,
+            "<=" => Op::Le,
+            ">" => Op::Gt,
+            ">=" => Op::Ge,
+            op => return Err(format!("unknown operator: {}", op)),
+        };
+        let value = value
+            .parse()
+            .map_err(|_| format!("invalid value: {}", value))?;
+        Ok(Predicate::Compare(name.to_string(), op, value))
+    }
+
+    /// Whether `values` satisfy the predicate, or an error if it names none of them.
+    fn holds(&self, values: &[(&'static str, i64)]) -> Result<bool, String> {
+        let (name, op, value) = match self {
+            Predicate::Done => return Ok(false),
+            Predicate::Compare(name, op, value) => (name, *op, *value),
+        };
+        let actual = values
+            .iter()
+            .find(|(known, _)| known == name)
+            .map(|&(_, actual)| actual)
+            .ok_or_else(|| {
+                let names: Vec<_> = values.iter().map(|(name, _)| *name).collect();
+                format!(
+                    "unknown value {}, expected one of: {}",
+                    name,
+                    names.join(", ")
+                )
+            })?;
+        Ok(match op {
+            Op::Eq => actual == value,
+            Op::Ne => actual != value,
+            Op::Lt => actual < value,
+            Op::Le => actual <= value,
+            Op::Gt => actual > value,
+            Op::Ge => actual >= value,
+        })
+    }
+}
+
+/// The current state with the states before it.
+struct Session<S> {
+    state: S,
+    steps: usize,
+    history: Vec<S>,
+    /// Whether `step` reported the end of the simulation.
+    done: bool,
+}
+
+impl<S: Steppable> Session<S> {
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let previous = self.state.clone();
+        if !self.state.step() {
+            self.state = previous;
+            self.done = true;
+            return false;
+        }
+        if self.history.len() == HISTORY {
+            self.history.remove(0);
+        }
+        self.history.push(previous);
+        self.steps += 1;
+        true
+    }
+
+    fn back(&mut self) -> bool {
+        match self.history.pop() {
+            Some(previous) => {
+                self.state = previous;
+                self.steps -= 1;
+                self.done = false;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<S: Steppable> fmt::Display for Session<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "step {}", self.steps)?;
+        for (name, value) in self.state.values() {
+            write!(f, "  {}={}", name, value)?;
+        }
+        if self.done {
+            write!(f, "  (done)")?;
+        }
+        Ok(())
+    }
+}
+
+fn count(arg: Option<&str>) -> Result<usize, String> {
+    match arg {
+        Some(arg) => arg.parse().map_err(|_| format!("invalid count: {}", arg)),
+        None => Ok(1),
+    }
+}
+
+/// Runs the commands read from `input` on `state`, writing replies to `output`.
+pub fn repl<S: Steppable>(state: S, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut session = Session {
+        state,
+        steps: 0,
+        history: Vec::new(),
+        done: false,
+    };
+    writeln!(output, "{}", session)?;
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let rest = Some(rest.trim()).filter(|rest| !rest.is_empty());
+        let reply = match command {
+            "" => continue,
+            "step" | "s" => count(rest).map(|n| {
+                (0..n).take_while(|_| session.step()).count();
+                session.to_string()
+            }),
+            "back" | "b" => count(rest).map(|n| {
+                (0..n).take_while(|_| session.back()).count();
+                session.to_string()
+            }),
+            "run-until" | "r" => Predicate::parse(rest.unwrap_or("")).and_then(|predicate| {
+                for _ in 0..MAX_RUN {
+                    if predicate.holds(&session.state.values())? || !session.step() {
+                        return Ok(session.to_string());
+                    }
+                }
+                Err(format!("gave up after {} steps\n{}", MAX_RUN, session))
+            }),
+            #[cfg(feature = "visualize")]
+            "show" => Ok(format!("{}\n{}", session.state.show().trim_end(), session)),
+            #[cfg(not(feature = "visualize"))]
+            "show" => Err(String::from(
+                "show needs aoc built with the visualize feature",
+            )),
+            "help" | "?" => Ok(String::from(
+                "commands: step [n], run-until <name> <op> <value>, run-until done, \
+                 show, back [n], quit",
+            )),
+            "quit" | "q" => break,
+            command => Err(format!("unknown command: {}", command)),
+        };
+        match reply {
+            Ok(reply) => writeln!(output, "{}", reply)?,
+            Err(e) => writeln!(output, "error: {}", e)?,
+        }
+    }
+    Ok(())
+}
+
+/// Parses a day's input and steps through it, reading commands from the
+/// reader and replying to the writer.
+type Start = fn(&str, &mut dyn BufRead, &mut dyn Write) -> Result<io::Result<()>, ParseError>;
+
+/// A day that can be stepped through.
+pub struct Entry {
+    pub day: u32,
+    start: Start,
+}
+
+/// Days that can be stepped through.
+pub const DAYS: &[Entry] = &[
+    #[cfg(feature = "week1")]
+    Entry {
+        day: 7,
+        start: |text, input, output| {
+            day7::Day7::parse(text).map(|dependencies| {
+                let assembly =
+                    day7::Assembly::new(&dependencies, day7::WORKERS.get(), day7::BASE_TIME.get());
+                repl(assembly, input, output)
+            })
+        },
+    },
+    #[cfg(all(feature = "week2", feature = "heavy"))]
+    Entry {
+        day: 9,
+        start: |text, input, output| {
+            day9::Day9::parse(text).map(|rules| repl(day9::Circle::new(&rules), input, output))
+        },
+    },
+    #[cfg(feature = "week2")]
+    Entry {
+        day: 10,
+        start: |text, input, output| {
+            day10::Day10::parse(text).map(|stars| repl(day10::Sky::new(stars), input, output))
+        },
+    },
+    #[cfg(feature = "week2")]
+    Entry {
+        day: 12,
+        start: |text, input, output| {
+            day12::Day12::parse(text).map(|pots| repl(day12::Garden::new(&pots), input, output))
+        },
+    },
+    #[cfg(feature = "week2")]
+    Entry {
+        day: 13,
+        start: |text, input, output| {
+            day13::Day13::parse(text).map(|mine| repl(day13::Traffic::new(&mine), input, output))
+        },
+    },
+];
+
+/// Parses day `day`'s input and steps through it interactively, or returns
+/// `None` if the day cannot be stepped through.
+pub fn session(
+    day: u32,
+    text: &str,
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> Option<Result<io::Result<()>, ParseError>> {
+    let entry = DAYS.iter().find(|entry| entry.day == day)?;
+    Some((entry.start)(text, &mut input, &mut output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up to a limit.
+    #[derive(Clone)]
+    struct Counter(i64);
+
+    impl Steppable for Counter {
+        fn step(&mut self) -> bool {
+            self.0 += 1;
+            self.0 <= 5
+        }
+        fn values(&self) -> Vec<(&'static str, i64)> {
+            vec![("count", self.0)]
+        }
+        #[cfg(feature = "visualize")]
+        fn show(&self) -> String {
+            "#".repeat(self.0 as usize)
+        }
+    }
+
+    fn replies(commands: &str) -> Vec<String> {
+        let mut output = Vec::new();
+        repl(Counter(0), commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(
+            replies("step\nstep 2\nback\nrun-until count >= 4\nrun-until done\nstep"),
+            vec![
+                "step 0  count=0",
+                "step 1  count=1",
+                "step 3  count=3",
+                "step 2  count=2",
+                "step 4  count=4",
+                "step 5  count=5  (done)",
+                "step 5  count=5  (done)",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "visualize")]
+    fn show() {
+        assert_eq!(
+            replies("step 2\nshow"),
+            vec![
+                "step 0  count=0",
+                "step 2  count=2",
+                "##",
+                "step 2  count=2"
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "visualize"))]
+    fn show_without_visualize() {
+        assert_eq!(
+            replies("show"),
+            vec![
+                "step 0  count=0",
+                "error: show needs aoc built with the visualize feature",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "week1")]
+    fn day_session() {
+        let text = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.";
+        let mut output = Vec::new();
+        session(7, text, "step 63\nrun-until done".as_bytes(), &mut output)
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "step 0  second=0  remaining=6  busy=1\n\
+             step 63  second=63  remaining=5  busy=2\n\
+             step 253  second=253  remaining=0  busy=0  (done)\n"
+        );
+    }
+
+    #[test]
+    fn day_without_session() {
+        assert!(session(1, "", "".as_bytes(), Vec::new()).is_none());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            replies("jump\nrun-until size > 1\nrun-until count ~ 1\nback\nquit\nstep"),
+            vec![
+                "step 0  count=0",
+                "error: unknown command: jump",
+                "error: unknown value size, expected one of: count",
+                "error: unknown operator: ~",
+                "step 0  count=0",
+            ]
+        );
+    }
+}
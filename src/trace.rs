@@ -0,0 +1,220 @@
+//! Structured events emitted by the days while solving, for `aoc run --trace`.
+//!
+//! Days emit events with the [`trace!`](crate::trace!) macro, which does
+//! nothing until [`start`] is called:
+//!
+//! ```
+//! aoc2018::trace!("assign", worker = 0, step = 'C');
+//! ```
+//!
+//! Each event is tagged with the day and part being solved on its thread, as
+//! set by the runner.
+//!
+//! Without the `trace` feature events are compiled out: the macro only checks
+//! its fields, and [`scope`] and [`quiet`] just call their closure.
+
+#[cfg(feature = "trace")]
+use std::cell::Cell;
+#[cfg(feature = "trace")]
+use std::io::Write;
+#[cfg(feature = "trace")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "trace")]
+use std::sync::Mutex;
+
+#[cfg(feature = "trace")]
+use crate::json::Value;
+use crate::runner::Part;
+
+#[cfg(feature = "trace")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// `day 7 part 2: assign second=0 worker=0 step=C`
+    Text,
+    /// One JSON object per line, with `day`, `part` and `event` first.
+    Jsonl,
+}
+
+/// Whether events are written, checked before building them.
+#[cfg(feature = "trace")]
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "trace")]
+static SINK: Mutex<Option<(Format, Box<dyn Write + Send>)>> = Mutex::new(None);
+
+#[cfg(feature = "trace")]
+thread_local! {
+    /// The day being solved on this thread, and its part (`None` while parsing).
+    static SCOPE: Cell<Option<(u32, Option<Part>)>> = const { Cell::new(None) };
+    /// Whether this thread is replaying work whose events were already written.
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Writes the events from now on to `out`.
+#[cfg(feature = "trace")]
+pub fn start(format: Format, out: Box<dyn Write + Send>) {
+    *SINK.lock().unwrap() = Some((format, out));
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+#[cfg(feature = "trace")]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Calls `f` with the events it emits tagged with `day` and `part`.
+#[cfg(feature = "trace")]
+pub fn scope<R>(day: u32, part: Option<Part>, f: impl FnOnce() -> R) -> R {
+    let outer = SCOPE.with(|scope| scope.replace(Some((day, part))));
+    let result = f();
+    SCOPE.with(|scope| scope.set(outer));
+    result
+}
+
+/// Calls `f` without writing the events it emits, for work done again such as
+/// a simulation replayed from its start.
+#[cfg(feature = "trace")]
+pub fn quiet<R>(f: impl FnOnce() -> R) -> R {
+    let outer = QUIET.with(|quiet| quiet.replace(true));
+    let result = f();
+    QUIET.with(|quiet| quiet.set(outer));
+    result
+}
+
+#[cfg(not(feature = "trace"))]
+pub fn scope<R>(_day: u32, _part: Option<Part>, f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+#[cfg(not(feature = "trace"))]
+pub fn quiet<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+/// Writes an event, tagged with the current scope. Use [`trace!`](crate::trace!)
+/// instead, which skips building the fields while tracing is off.
+#[cfg(feature = "trace")]
+pub fn emit(event: &str, fields: Vec<(&'static str, Value)>) {
+    if QUIET.with(Cell::get) {
+        return;
+    }
+    let scope = SCOPE.with(Cell::get);
+    if let Some((format, out)) = SINK.lock().unwrap().as_mut() {
+        // A trace that cannot be written is not worth failing the run for
+        let _ = writeln!(out, "{}", line(*format, scope, event, fields));
+    }
+}
+
+#[cfg(feature = "trace")]
+fn line(
+    format: Format,
+    scope: Option<(u32, Option<Part>)>,
+    event: &str,
+    fields: Vec<(&'static str, Value)>,
+) -> String {
+    match format {
+        Format::Text => {
+            let mut line = match scope {
+                Some((day, Some(part))) => format!("day {} part {}: {}", day, part, event),
+                Some((day, None)) => format!("day {} parse: {}", day, event),
+                None => event.to_string(),
+            };
+            for (name, value) in fields {
+                // Strings unquoted, unlike in JSON
+                match value {
+                    Value::String(value) => line += &format!(" {}={}", name, value),
+                    value => line += &format!(" {}={}", name, value),
+                }
+            }
+            line
+        }
+        Format::Jsonl => {
+            let (day, part) = match scope {
+                Some((day, part)) => (Some(day), part.map(|part| part.to_string())),
+                None => (None, None),
+            };
+            let mut object = vec![
+                ("day", Value::from(day)),
+                ("part", part.map_or(Value::Null, Value::Number)),
+                ("event", Value::from(event)),
+            ];
+            object.extend(fields);
+            Value::object(object).to_string()
+        }
+    }
+}
+
+/// Emits an event named by a string with `name = value` fields, each value
+/// converted with `json::Value::from`.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! trace {
+    ( $event:expr $( , $name:ident = $value:expr )* $(,)? ) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit(
+                $event,
+                vec![ $( (stringify!($name), $crate::json::Value::from($value)) ),* ],
+            );
+        }
+    };
+}
+
+/// Checks the event and its fields, which are never evaluated.
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! trace {
+    ( $event:expr $( , $name:ident = $value:expr )* $(,)? ) => {
+        if false {
+            let _ = ($event, $( &$value ),*);
+        }
+    };
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod tests {
+    use super::*;
+
+    fn fields() -> Vec<(&'static str, Value)> {
+        vec![("worker", Value::from(1)), ("step", Value::from('C'))]
+    }
+
+    #[test]
+    fn text_lines() {
+        assert_eq!(
+            line(Format::Text, Some((7, Some(Part::Two))), "assign", fields()),
+            "day 7 part 2: assign worker=1 step=C"
+        );
+        assert_eq!(
+            line(Format::Text, Some((4, None)), "sleep", Vec::new()),
+            "day 4 parse: sleep"
+        );
+    }
+
+    #[test]
+    fn jsonl_lines() {
+        assert_eq!(
+            line(
+                Format::Jsonl,
+                Some((7, Some(Part::Two))),
+                "assign",
+                fields()
+            ),
+            r#"{"day":7,"part":2,"event":"assign","worker":1,"step":"C"}"#
+        );
+        assert_eq!(
+            line(Format::Jsonl, None, "sleep", Vec::new()),
+            r#"{"day":null,"part":null,"event":"sleep"}"#
+        );
+    }
+
+    #[test]
+    fn scopes_nest() {
+        scope(4, None, || {
+            scope(7, Some(Part::One), || {
+                assert_eq!(SCOPE.with(Cell::get), Some((7, Some(Part::One))))
+            });
+            assert_eq!(SCOPE.with(Cell::get), Some((4, None)));
+        });
+        assert_eq!(SCOPE.with(Cell::get), None);
+    }
+}
@@ -0,0 +1,73 @@
+//! Polls files for changes by their modification time, portably.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Files being watched, with the modification time they had when last polled.
+pub struct Watcher {
+    /// `None` for files that could not be read, so their creation counts as a change.
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+impl Watcher {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Watcher {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let time = modified(&path);
+                    (path, time)
+                })
+                .collect(),
+        }
+    }
+
+    /// Files modified, created or removed since the last poll.
+    pub fn poll(&mut self) -> Vec<&Path> {
+        let mut changed = Vec::new();
+        for (path, time) in self.files.iter_mut() {
+            let now = modified(path);
+            if now != *time {
+                *time = now;
+                changed.push(path.as_path());
+            }
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::File;
+    use std::time::Duration;
+
+    #[test]
+    fn reports_each_change_once() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (input, missing) = (dir.join("day1.txt"), dir.join("day1-alt.txt"));
+        fs::write(&input, "+1").unwrap();
+        let mut watcher = Watcher::new([input.clone(), missing.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        let later = SystemTime::now() + Duration::from_secs(10);
+        File::options()
+            .write(true)
+            .open(&input)
+            .and_then(|file| file.set_modified(later))
+            .unwrap();
+        assert_eq!(watcher.poll(), vec![input.as_path()]);
+        assert!(watcher.poll().is_empty());
+
+        fs::write(&missing, "-1").unwrap();
+        assert_eq!(watcher.poll(), vec![missing.as_path()]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
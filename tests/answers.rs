@@ -0,0 +1,62 @@
+#[cfg(any(feature = "week1", feature = "week2"))]
+fn verify(day: u32) {
+    use aoc2018::answers::{self, Answers, Outcome};
+    use aoc2018::runner::{self, Part};
+
+    let answers = Answers::parse(&aoc2018::read_answers().unwrap()).unwrap();
+    let input = aoc2018::read_input(day).unwrap();
+    let result = runner::find(day).unwrap().run(&input, &Part::ALL).unwrap();
+    for (part, outcome) in answers::check(&answers, &result) {
+        assert_ne!(
+            outcome,
+            Outcome::Unsolved,
+            "day{} part {} has no recorded answer",
+            day,
+            part
+        );
+        assert_eq!(outcome, Outcome::Pass, "day{} part {}", day, part);
+    }
+}
+
+macro_rules! verify_days {
+    ( $( $(#[$attr:meta])* $name:ident: $day:expr ),* ) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                verify($day);
+            }
+        )*
+    };
+}
+
+verify_days!(
+    #[cfg(feature = "week1")]
+    day1: 1,
+    #[cfg(feature = "week1")]
+    day2: 2,
+    #[cfg(feature = "week1")]
+    day3: 3,
+    #[cfg(feature = "week1")]
+    day4: 4,
+    #[cfg(feature = "week1")]
+    day5: 5,
+    #[cfg(feature = "week1")]
+    day6: 6,
+    #[cfg(feature = "week1")]
+    day7: 7,
+    #[cfg(feature = "week2")]
+    day8: 8,
+    #[cfg(all(feature = "week2", feature = "heavy"))]
+    day9: 9,
+    #[cfg(feature = "week2")]
+    day10: 10,
+    #[cfg(feature = "week2")]
+    day11: 11,
+    #[cfg(feature = "week2")]
+    day12: 12,
+    #[cfg(feature = "week2")]
+    day13: 13,
+    #[cfg(all(feature = "week2", feature = "heavy"))]
+    day14: 14
+);
@@ -0,0 +1,116 @@
+//! Checks that implementations which should agree do so on random small inputs.
+//!
+//! Inputs are generated from `$AOC_SEED` (default: 2018), which is reported
+//! along with the minimized input when the implementations disagree.
+
+#![cfg(feature = "week2")]
+
+use std::env;
+use std::fmt::{Debug, Display};
+
+use aoc2018::day10::{self, Day10, Stars};
+use aoc2018::day13::{self, Day13};
+#[cfg(feature = "heavy")]
+use aoc2018::day9::{Day9, ListGame, VecGame};
+use aoc2018::differential::{self, Shrink};
+#[cfg(feature = "heavy")]
+use aoc2018::gen::Marbles;
+use aoc2018::gen::{StarField, TrackMap};
+use aoc2018::point::Point;
+use aoc2018::rng::Rng;
+use aoc2018::Solution;
+
+const CASES: usize = 200;
+
+fn seed() -> u64 {
+    env::var("AOC_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(2018)
+}
+
+fn check<I, T>(generate: impl Fn(&mut Rng) -> I, left: impl Fn(&I) -> T, right: impl Fn(&I) -> T)
+where
+    I: Shrink + Display,
+    T: PartialEq + Debug,
+{
+    if let Err(disagreement) = differential::check(seed(), CASES, generate, left, right) {
+        panic!("{}", disagreement);
+    }
+}
+
+#[test]
+#[cfg(feature = "heavy")]
+fn day9_vec_and_list_games_agree() {
+    check(
+        |rng| Marbles::generate(rng, 20),
+        |marbles| {
+            let rules = Day9::parse(&marbles.to_string()).unwrap();
+            let mut game = VecGame::new(rules.players);
+            (0..rules.turns).for_each(|_| game.next());
+            game.scores
+        },
+        |marbles| {
+            let rules = Day9::parse(&marbles.to_string()).unwrap();
+            let mut game = ListGame::new(rules.players);
+            (0..rules.turns).for_each(|_| game.next());
+            game.scores
+        },
+    );
+}
+
+/// Day 10's search limit.
+const MAX_ITERATIONS: usize = 11000;
+
+/// The last of the smallest heights within the search limit, found by moving
+/// the stars directly to every second.
+fn exhaustive_convergence(stars: &Stars) -> Option<(usize, Vec<Point<i32>>)> {
+    let at = |time: usize| -> Vec<Point<i32>> {
+        let time = time as i32;
+        stars
+            .positions
+            .iter()
+            .zip(stars.velocities.iter())
+            .map(|(&p, &v)| p + v * time)
+            .collect()
+    };
+    let height = |time| {
+        let ys: Vec<_> = at(time).iter().map(|p| p.y).collect();
+        ys.iter().max().unwrap() - ys.iter().min().unwrap()
+    };
+    let heights: Vec<_> = (0..MAX_ITERATIONS).map(height).collect();
+    let smallest = *heights.iter().min().unwrap();
+    let time = heights.iter().rposition(|&h| h == smallest).unwrap();
+    if time == MAX_ITERATIONS - 1 {
+        return None;
+    }
+    Some((time, at(time)))
+}
+
+#[test]
+fn day10_convergence_matches_exhaustive_search() {
+    check(
+        |rng| StarField::generate(rng, 12),
+        |field| day10::converge(&Day10::parse(&field.to_string()).unwrap()),
+        |field| exhaustive_convergence(&Day10::parse(&field.to_string()).unwrap()),
+    );
+}
+
+#[test]
+fn day13_first_crash_matches_crash_removal() {
+    check(
+        |rng| TrackMap::generate(rng, 8),
+        |map| {
+            let mine = Day13::parse(&map.to_string()).unwrap();
+            Day13::part1(&mine).map(|crash| Point::new(crash.x, crash.y))
+        },
+        |map| {
+            let mine = Day13::parse(&map.to_string()).unwrap();
+            let mut first = None;
+            day13::remove_crashes(&mine, |_, position| {
+                first.get_or_insert(position);
+            });
+            first
+        },
+    );
+}